@@ -1,25 +1,40 @@
 // src/ast.rs
 
+use crate::token::Span;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum ASTNode {
     Program(Vec<ASTNode>),
-    Statement(Box<ASTNode>),
+    Statement {
+        span: Span, // Anfang der Anweisung im Quelltext
+        node: Box<ASTNode>,
+    },
     Block(Vec<ASTNode>), // Bereits hinzugefügt
     Number(f64),
     String(String),
     Boolean(bool),
+    Null,
     Variable(String),
     BinaryOp {
         left: Box<ASTNode>,
         operator: String,
         right: Box<ASTNode>,
     },
+    Comparison {
+        left: Box<ASTNode>,
+        comparisons: Vec<(String, ASTNode)>,
+    },
     UnaryOp {
         operator: String,
         operand: Box<ASTNode>,
     },
     Assignment {
-        name: String,
+        targets: Vec<ASTNode>, // Mehrere Ziele bei verketteter Zuweisung: a = b = 0
+        value: Box<ASTNode>,
+    },
+    AugAssignment {
+        target: Box<ASTNode>,
+        operator: String,
         value: Box<ASTNode>,
     },
     IfStatement {
@@ -27,22 +42,104 @@ pub enum ASTNode {
         then_branch: Box<ASTNode>,
         else_branch: Option<Box<ASTNode>>,
     },
+    ConditionalExpression {
+        condition: Box<ASTNode>,
+        then_expr: Box<ASTNode>,
+        else_expr: Box<ASTNode>,
+    },
     WhileLoop {
         condition: Box<ASTNode>,
         body: Box<ASTNode>,
     },
+    ClassDef {
+        name: String,
+        base: Option<Box<ASTNode>>,
+        body: Box<ASTNode>,
+    },
     FunctionDef {
         name: String,
         params: Vec<String>,
         body: Box<ASTNode>,
     },
     FunctionCall {
-        name: String,
+        callee: Box<ASTNode>,
         args: Vec<ASTNode>,
+        kwargs: Vec<(String, ASTNode)>,
+        span: Span, // Position der öffnenden Klammer, für den Traceback
+    },
+    Lambda {
+        params: Vec<String>,
+        body: Box<ASTNode>,
+    },
+    Attribute {
+        object: Box<ASTNode>,
+        name: String,
+    },
+    Index {
+        object: Box<ASTNode>,
+        index: Box<ASTNode>,
     },
     Return(Box<ASTNode>),
     Print(Box<ASTNode>),
+    Starred(Box<ASTNode>), // *rest beim Entpacken
     List(Vec<ASTNode>),    // Bereits hinzugefügt
     Tuple(Vec<ASTNode>),   // Bereits hinzugefügt
+    Dict(Vec<(ASTNode, ASTNode)>),
+    Set(Vec<ASTNode>),
+    ListComprehension {
+        element: Box<ASTNode>,
+        clauses: Vec<ComprehensionClause>,
+    },
+    SetComprehension {
+        element: Box<ASTNode>,
+        clauses: Vec<ComprehensionClause>,
+    },
+    DictComprehension {
+        key: Box<ASTNode>,
+        value: Box<ASTNode>,
+        clauses: Vec<ComprehensionClause>,
+    },
+    GeneratorExpression {
+        element: Box<ASTNode>,
+        clauses: Vec<ComprehensionClause>,
+    },
+    TryStatement {
+        body: Box<ASTNode>,
+        handlers: Vec<ExceptHandler>,
+        else_branch: Option<Box<ASTNode>>,
+        finally_branch: Option<Box<ASTNode>>,
+    },
+    Raise(Option<Box<ASTNode>>), // Ohne Ausdruck: aktuelle Ausnahme erneut auslösen
+    Import(Vec<(String, Option<String>)>), // import a, b as c
+    FromImport {
+        module: String,
+        names: Vec<(String, Option<String>)>, // from a import x, y as z
+    },
+    With {
+        context: Box<ASTNode>,
+        name: Option<String>, // with ausdruck as name:
+        body: Box<ASTNode>,
+    },
+    Assert {
+        condition: Box<ASTNode>,
+        message: Option<Box<ASTNode>>,
+        span: Span, // Bereich der Bedingung; ihr Quelltext dient als Meldung, wenn keine angegeben ist
+    },
     // Weitere Knoten können hier hinzugefügt werden
 }
+
+// Ein `except Typ as name:`-Zweig; ohne Typ fängt er jede Ausnahme
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExceptHandler {
+    pub exception_type: Option<ASTNode>,
+    pub name: Option<String>,
+    pub body: ASTNode,
+}
+
+// Ein `for ziel in iterable if bedingung ...`-Teil einer Comprehension
+#[derive(Debug, Clone, PartialEq)]
+pub struct ComprehensionClause {
+    pub target: ASTNode,
+    pub iterable: ASTNode,
+    pub conditions: Vec<ASTNode>,
+}
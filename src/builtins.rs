@@ -0,0 +1,283 @@
+// src/builtins.rs
+
+use crate::exceptions::{self, Exception, Result};
+use crate::interpreter::{type_name, Interpreter, Value};
+use crate::modules;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+const BUILTINS: &[&str] = &[
+    "map", "filter", "reduce", "sorted", "min", "max", "str", "len", "super", "isinstance", "issubclass",
+];
+
+pub fn lookup(name: &str) -> Option<Value> {
+    if BUILTINS.contains(&name) {
+        Some(Value::Builtin(name.to_string()))
+    } else {
+        None
+    }
+}
+
+pub fn call(
+    interpreter: &mut Interpreter,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    if exceptions::NATIVE_METHODS.contains(&name) {
+        reject_kwargs(name, &kwargs)?;
+        return exceptions::call_native_method(interpreter, name, args);
+    }
+    // Funktionen eingebauter Module heißen "modul.funktion"
+    if name.contains('.') {
+        return modules::call_native(interpreter, name, args, kwargs);
+    }
+    match name {
+        "map" => {
+            reject_kwargs(name, &kwargs)?;
+            if args.len() < 2 {
+                return Err(Exception::type_error(
+                    "map() erwartet eine Funktion und mindestens ein Iterable",
+                ));
+            }
+            let func = &args[0];
+            let mut columns = Vec::new();
+            for iterable in &args[1..] {
+                columns.push(interpreter.iterate(iterable)?);
+            }
+            let len = columns.iter().map(|c| c.len()).min().unwrap_or(0);
+            let mut result = Vec::new();
+            for i in 0..len {
+                let call_args = columns.iter().map(|c| c[i].clone()).collect();
+                result.push(interpreter.call_function(func, call_args, Vec::new())?);
+            }
+            Ok(Value::list(result))
+        }
+        "filter" => {
+            reject_kwargs(name, &kwargs)?;
+            if args.len() != 2 {
+                return Err(Exception::type_error("filter() erwartet genau 2 Argumente"));
+            }
+            let mut result = Vec::new();
+            for item in interpreter.iterate(&args[1])? {
+                let keep = if args[0] == Value::Null {
+                    item.clone()
+                } else {
+                    interpreter.call_function(&args[0], vec![item.clone()], Vec::new())?
+                };
+                if interpreter.is_truthy(&keep)? {
+                    result.push(item);
+                }
+            }
+            Ok(Value::list(result))
+        }
+        "reduce" => {
+            reject_kwargs(name, &kwargs)?;
+            if args.len() != 2 && args.len() != 3 {
+                return Err(Exception::type_error("reduce() erwartet 2 oder 3 Argumente"));
+            }
+            let mut items = interpreter.iterate(&args[1])?.into_iter();
+            let mut acc = match args.get(2) {
+                Some(initial) => initial.clone(),
+                None => items.next().ok_or_else(|| {
+                    Exception::type_error("reduce() einer leeren Sequenz ohne Startwert")
+                })?,
+            };
+            for item in items {
+                acc = interpreter.call_function(&args[0], vec![acc, item], Vec::new())?;
+            }
+            Ok(acc)
+        }
+        "sorted" => {
+            if args.len() != 1 {
+                return Err(Exception::type_error("sorted() erwartet genau 1 Positionsargument"));
+            }
+            let items = interpreter.iterate(&args[0])?;
+            Ok(Value::list(sort_values(interpreter, items, kwargs, "sorted")?))
+        }
+        "str" => {
+            reject_kwargs(name, &kwargs)?;
+            match args.as_slice() {
+                [] => Ok(Value::String(String::new())),
+                [value] => Ok(Value::String(interpreter.value_to_string(value)?)),
+                _ => Err(Exception::type_error("str() erwartet höchstens 1 Argument")),
+            }
+        }
+        "len" => {
+            reject_kwargs(name, &kwargs)?;
+            match args.as_slice() {
+                [value] => Ok(Value::Number(interpreter.length(value)? as f64)),
+                _ => Err(Exception::type_error("len() erwartet genau 1 Argument")),
+            }
+        }
+        "super" => {
+            reject_kwargs(name, &kwargs)?;
+            interpreter.make_super(args)
+        }
+        "isinstance" | "issubclass" => {
+            reject_kwargs(name, &kwargs)?;
+            let [value, classinfo] = args.as_slice() else {
+                return Err(Exception::type_error(format!("{}() erwartet genau 2 Argumente", name)));
+            };
+            let class = match (name, value) {
+                ("isinstance", Value::Instance(instance)) => Some(Rc::clone(&instance.class)),
+                ("isinstance", _) => None,
+                (_, Value::Class(class)) => Some(Rc::clone(class)),
+                (_, other) => {
+                    return Err(Exception::type_error(format!(
+                        "issubclass() erwartet eine Klasse, nicht '{}'",
+                        type_name(other)
+                    )))
+                }
+            };
+            // Wie in Python darf das zweite Argument ein Tupel von Klassen sein
+            let candidates = match classinfo {
+                Value::Tuple(items) => items.clone(),
+                other => vec![other.clone()],
+            };
+            let mut result = false;
+            for candidate in &candidates {
+                let Value::Class(candidate) = candidate else {
+                    return Err(Exception::type_error(format!(
+                        "{}(): zweites Argument muss eine Klasse oder ein Tupel von Klassen sein",
+                        name
+                    )));
+                };
+                result |= class.as_ref().is_some_and(|class| class.is_subclass(candidate));
+            }
+            Ok(Value::Boolean(result))
+        }
+        "min" | "max" => {
+            let mut key = Value::Null;
+            let mut default = None;
+            for (kw, val) in kwargs {
+                match kw.as_str() {
+                    "key" => key = val,
+                    "default" => default = Some(val),
+                    _ => {
+                        return Err(Exception::type_error(format!(
+                            "Unbekanntes Schlüsselwortargument '{}' für {}()",
+                            kw, name
+                        )))
+                    }
+                }
+            }
+            let items = match args.len() {
+                0 => {
+                    return Err(Exception::type_error(format!(
+                        "{}() erwartet mindestens 1 Argument",
+                        name
+                    )))
+                }
+                1 => interpreter.iterate(&args[0])?,
+                _ => args,
+            };
+            let wanted = if name == "min" {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            };
+            let mut best: Option<(Value, Value)> = None;
+            for item in items {
+                let k = if key == Value::Null {
+                    item.clone()
+                } else {
+                    interpreter.call_function(&key, vec![item.clone()], Vec::new())?
+                };
+                let better = match &best {
+                    None => true,
+                    Some((best_key, _)) => {
+                        interpreter.compare_values(&k, best_key, "<")? == Some(wanted)
+                    }
+                };
+                if better {
+                    best = Some((k, item));
+                }
+            }
+            match (best, default) {
+                (Some((_, item)), _) => Ok(item),
+                (None, Some(default)) => Ok(default),
+                (None, None) => Err(Exception::value_error(format!("{}() einer leeren Sequenz", name))),
+            }
+        }
+        _ => Err(Exception::new("NameError", format!("Unbekannte eingebaute Funktion: {}", name))),
+    }
+}
+
+// Stabile Sortierung mit den Schlüsselwortargumenten `key` und `reverse`,
+// gemeinsam genutzt von sorted() und list.sort()
+pub fn sort_values(
+    interpreter: &mut Interpreter,
+    items: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+    name: &str,
+) -> Result<Vec<Value>> {
+    let mut key = Value::Null;
+    let mut reverse = false;
+    for (kw, val) in kwargs {
+        match kw.as_str() {
+            "key" => key = val,
+            "reverse" => reverse = interpreter.is_truthy(&val)?,
+            _ => {
+                return Err(Exception::type_error(format!(
+                    "Unbekanntes Schlüsselwortargument '{}' für {}()",
+                    kw, name
+                )))
+            }
+        }
+    }
+    let mut keyed: Vec<(Value, Value)> = Vec::new();
+    for item in items {
+        let k = if key == Value::Null {
+            item.clone()
+        } else {
+            interpreter.call_function(&key, vec![item.clone()], Vec::new())?
+        };
+        keyed.push((k, item));
+    }
+    let keyed = merge_sort(interpreter, keyed, reverse)?;
+    Ok(keyed.into_iter().map(|(_, item)| item).collect())
+}
+
+// Eigene Mergesort statt `sort_by`: benutzerdefinierte Vergleiche müssen keine totale Ordnung
+// sein und dürfen fehlschlagen; der erste Fehler bricht die Sortierung ab. Wie in Python wird
+// nur `<` benutzt, und bei Gleichheit oder ungeordneten Werten bleibt die Reihenfolge erhalten.
+fn merge_sort(
+    interpreter: &mut Interpreter,
+    mut items: Vec<(Value, Value)>,
+    reverse: bool,
+) -> Result<Vec<(Value, Value)>> {
+    if items.len() < 2 {
+        return Ok(items);
+    }
+    let right = items.split_off(items.len() / 2);
+    let left = merge_sort(interpreter, items, reverse)?;
+    let right = merge_sort(interpreter, right, reverse)?;
+    let mut merged = Vec::with_capacity(left.len() + right.len());
+    let mut left = left.into_iter().peekable();
+    let mut right = right.into_iter().peekable();
+    while let (Some((a, _)), Some((b, _))) = (left.peek(), right.peek()) {
+        // Das rechte Element kommt nur zuerst, wenn es echt kleiner (bzw. größer) ist
+        let (first, second) = if reverse { (a, b) } else { (b, a) };
+        let ordering = interpreter.compare_values(first, second, "<")?;
+        let next = if ordering == Some(Ordering::Less) {
+            right.next()
+        } else {
+            left.next()
+        };
+        merged.extend(next);
+    }
+    merged.extend(left);
+    merged.extend(right);
+    Ok(merged)
+}
+
+pub fn reject_kwargs(name: &str, kwargs: &[(String, Value)]) -> Result<()> {
+    if let Some((kw, _)) = kwargs.first() {
+        return Err(Exception::type_error(format!(
+            "{}() akzeptiert kein Schlüsselwortargument '{}'",
+            name, kw
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,413 @@
+// src/csv.rs
+
+use crate::dict::Dict;
+use crate::exceptions::{Exception, Result};
+use crate::files::os_error;
+use crate::interpreter::{type_name, Interpreter, Value};
+use crate::modules::expect_args;
+use std::fs;
+
+pub const FUNCTIONS: &[&str] = &[
+    "read", "read_dicts", "write", "write_dicts", "parse", "format",
+];
+
+pub fn call(
+    interpreter: &mut Interpreter,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    let method = format!("csv.{}", name);
+    let (dialect, fieldnames) = Dialect::from_kwargs(&method, kwargs, name == "write_dicts")?;
+    match name {
+        // Quelle ist ein Pfad oder ein Objekt mit `read()`
+        "read" | "read_dicts" | "parse" => {
+            expect_args(&method, &args, 1, 1)?;
+            let text = match name {
+                "parse" => expect_string(&method, &args[0])?,
+                _ => read_source(interpreter, &method, &args[0])?,
+            };
+            let rows = dialect.parse(&text)?;
+            if name == "read_dicts" {
+                return records(rows);
+            }
+            Ok(Value::list(rows.into_iter().map(string_list).collect()))
+        }
+        "format" => {
+            expect_args(&method, &args, 1, 1)?;
+            let rows = rows(interpreter, &args[0])?;
+            Ok(Value::String(dialect.format(interpreter, &rows)?))
+        }
+        // Ziel ist ein Pfad oder ein Objekt mit `write()`
+        "write" | "write_dicts" => {
+            expect_args(&method, &args, 2, 2)?;
+            let rows = match name {
+                "write" => rows(interpreter, &args[1])?,
+                _ => dict_rows(interpreter, &args[1], fieldnames)?,
+            };
+            let text = dialect.format(interpreter, &rows)?;
+            match &args[0] {
+                Value::String(path) => {
+                    fs::write(path, text).map_err(|error| os_error(error, path))?
+                }
+                target => {
+                    let write = interpreter.get_attribute(target.clone(), "write")?;
+                    interpreter.call_function(&write, vec![Value::String(text)], Vec::new())?;
+                }
+            }
+            Ok(Value::Null)
+        }
+        _ => Err(Exception::attribute_error(format!(
+            "Modul 'csv' hat kein Attribut '{}'",
+            name
+        ))),
+    }
+}
+
+fn expect_string(method: &str, value: &Value) -> Result<String> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        other => Err(Exception::type_error(format!(
+            "{}() erwartet 'str', nicht '{}'",
+            method,
+            type_name(other)
+        ))),
+    }
+}
+
+fn read_source(interpreter: &mut Interpreter, method: &str, source: &Value) -> Result<String> {
+    if let Value::String(path) = source {
+        return fs::read_to_string(path).map_err(|error| os_error(error, path));
+    }
+    let read = interpreter.get_attribute(source.clone(), "read")?;
+    let text = interpreter.call_function(&read, Vec::new(), Vec::new())?;
+    expect_string(method, &text)
+}
+
+fn string_list(row: Vec<String>) -> Value {
+    Value::list(row.into_iter().map(Value::String).collect())
+}
+
+// Die erste Zeile liefert die Schlüssel; fehlende Felder werden None
+fn records(rows: Vec<Vec<String>>) -> Result<Value> {
+    let mut rows = rows.into_iter();
+    let Some(header) = rows.next() else {
+        return Ok(Value::list(Vec::new()));
+    };
+    let mut records = Vec::new();
+    for (line, row) in rows.enumerate() {
+        if row.len() > header.len() {
+            return Err(Exception::new(
+                "CSVError",
+                format!(
+                    "Datensatz {} hat {} Felder, die Kopfzeile nur {}",
+                    line + 1,
+                    row.len(),
+                    header.len()
+                ),
+            ));
+        }
+        let mut record = Dict::new();
+        let mut fields = row.into_iter();
+        for key in &header {
+            let value = fields.next().map_or(Value::Null, Value::String);
+            record.insert(Value::String(key.clone()), value)?;
+        }
+        records.push(Value::dict(record));
+    }
+    Ok(Value::list(records))
+}
+
+// Zeilen zum Schreiben: jede Zeile ist eine beliebige iterierbare Folge von Feldern
+fn rows(interpreter: &mut Interpreter, value: &Value) -> Result<Vec<Vec<Value>>> {
+    let mut rows = Vec::new();
+    for row in interpreter.iterate(value)? {
+        rows.push(interpreter.iterate(&row)?);
+    }
+    Ok(rows)
+}
+
+// Dicts werden in der Reihenfolge der Feldnamen geschrieben, mit Kopfzeile. Ohne `fieldnames`
+// gelten die Schlüssel des ersten Datensatzes.
+fn dict_rows(
+    interpreter: &mut Interpreter,
+    value: &Value,
+    fieldnames: Option<Value>,
+) -> Result<Vec<Vec<Value>>> {
+    let records = interpreter.iterate(value)?;
+    let fieldnames = match fieldnames {
+        Some(names) => interpreter.iterate(&names)?,
+        None => match records.first() {
+            Some(Value::Dict(dict)) => dict.borrow().keys().cloned().collect(),
+            _ => Vec::new(),
+        },
+    };
+    let mut rows = vec![fieldnames.clone()];
+    for record in records {
+        let Value::Dict(dict) = record else {
+            return Err(Exception::type_error(format!(
+                "csv.write_dicts() erwartet Dicts als Datensätze, nicht '{}'",
+                type_name(&record)
+            )));
+        };
+        let dict = dict.borrow();
+        for key in dict.keys() {
+            if !fieldnames.iter().any(|name| name == key) {
+                return Err(Exception::value_error(format!(
+                    "Datensatz enthält Feld {}, das nicht in fieldnames steht",
+                    interpreter.value_to_string(key)?
+                )));
+            }
+        }
+        let mut row = Vec::new();
+        for name in &fieldnames {
+            row.push(dict.get(name)?.cloned().unwrap_or(Value::Null));
+        }
+        rows.push(row);
+    }
+    Ok(rows)
+}
+
+// Trennzeichen und Anführungszeichen; Standard wie in Python ',' und '"'
+struct Dialect {
+    delimiter: char,
+    quotechar: char,
+}
+
+impl Dialect {
+    fn from_kwargs(
+        method: &str,
+        kwargs: Vec<(String, Value)>,
+        accepts_fieldnames: bool,
+    ) -> Result<(Dialect, Option<Value>)> {
+        let mut dialect = Dialect {
+            delimiter: ',',
+            quotechar: '"',
+        };
+        let mut fieldnames = None;
+        for (key, value) in kwargs {
+            match key.as_str() {
+                "delimiter" => dialect.delimiter = single_char(method, &key, &value)?,
+                "quotechar" => dialect.quotechar = single_char(method, &key, &value)?,
+                "fieldnames" if accepts_fieldnames => fieldnames = Some(value),
+                _ => {
+                    return Err(Exception::type_error(format!(
+                        "Unbekanntes Schlüsselwortargument '{}' für {}()",
+                        key, method
+                    )))
+                }
+            }
+        }
+        if dialect.delimiter == dialect.quotechar || matches!(dialect.delimiter, '\n' | '\r') {
+            return Err(Exception::value_error(format!(
+                "{}(): ungültiges Trennzeichen {:?}",
+                method, dialect.delimiter
+            )));
+        }
+        Ok((dialect, fieldnames))
+    }
+
+    // Felder in Anführungszeichen dürfen Trennzeichen und Zeilenumbrüche enthalten; ein
+    // verdoppeltes Anführungszeichen steht für eines. Leere Zeilen werden übersprungen.
+    fn parse(&self, text: &str) -> Result<Vec<Vec<String>>> {
+        let mut rows = Vec::new();
+        let mut row = Vec::new();
+        let mut field = String::new();
+        let mut quoted = false; // Feld begann mit einem Anführungszeichen
+        let mut in_quotes = false;
+        let mut line = 1;
+        let mut chars = text.chars().peekable();
+        while let Some(c) = chars.next() {
+            if in_quotes {
+                if c == self.quotechar {
+                    if chars.peek() == Some(&self.quotechar) {
+                        chars.next();
+                        field.push(c);
+                    } else {
+                        in_quotes = false;
+                    }
+                } else {
+                    if c == '\n' {
+                        line += 1;
+                    }
+                    field.push(c);
+                }
+            } else if c == self.delimiter {
+                row.push(std::mem::take(&mut field));
+                quoted = false;
+            } else if c == '\n' || c == '\r' {
+                if c == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                if !row.is_empty() || !field.is_empty() || quoted {
+                    row.push(std::mem::take(&mut field));
+                    rows.push(std::mem::take(&mut row));
+                }
+                quoted = false;
+                line += 1;
+            } else if c == self.quotechar && field.is_empty() && !quoted {
+                quoted = true;
+                in_quotes = true;
+            } else if quoted {
+                return Err(Exception::new(
+                    "CSVError",
+                    format!(
+                        "Zeile {}: {:?} nach schließendem Anführungszeichen erwartet, gefunden {:?}",
+                        line, self.delimiter, c
+                    ),
+                ));
+            } else {
+                field.push(c);
+            }
+        }
+        if in_quotes {
+            return Err(Exception::new(
+                "CSVError",
+                format!("Zeile {}: Anführungszeichen wird nicht geschlossen", line),
+            ));
+        }
+        if !row.is_empty() || !field.is_empty() || quoted {
+            row.push(field);
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+
+    // Jede Zeile endet mit '\n'; None wird zum leeren Feld
+    fn format(&self, interpreter: &mut Interpreter, rows: &[Vec<Value>]) -> Result<String> {
+        let mut output = String::new();
+        for row in rows {
+            for (i, value) in row.iter().enumerate() {
+                if i > 0 {
+                    output.push(self.delimiter);
+                }
+                let field = match value {
+                    Value::Null => String::new(),
+                    value => interpreter.value_to_string(value)?,
+                };
+                output.push_str(&self.quote(&field));
+            }
+            output.push('\n');
+        }
+        Ok(output)
+    }
+
+    // Anführungszeichen nur, wenn das Feld sonst nicht eindeutig wieder einzulesen wäre
+    fn quote(&self, field: &str) -> String {
+        let needs_quotes = field
+            .chars()
+            .any(|c| c == self.delimiter || c == self.quotechar || c == '\n' || c == '\r');
+        if !needs_quotes {
+            return field.to_string();
+        }
+        let quote = self.quotechar.to_string();
+        format!("{0}{1}{0}", quote, field.replace(&quote, &quote.repeat(2)))
+    }
+}
+
+fn single_char(method: &str, key: &str, value: &Value) -> Result<char> {
+    if let Value::String(s) = value {
+        let mut chars = s.chars();
+        if let (Some(c), None) = (chars.next(), chars.next()) {
+            return Ok(c);
+        }
+    }
+    Err(Exception::type_error(format!(
+        "{}(): {} muss eine Zeichenkette aus genau einem Zeichen sein",
+        method, key
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn dialect(delimiter: char) -> Dialect {
+        Dialect {
+            delimiter,
+            quotechar: '"',
+        }
+    }
+
+    fn parse(text: &str) -> Vec<Vec<String>> {
+        dialect(',').parse(text).expect("CSV sollte gültig sein")
+    }
+
+    fn parse_error(text: &str) -> String {
+        match dialect(',').parse(text) {
+            Err(Exception::Builtin { kind: "CSVError", message }) => message,
+            other => panic!("CSVError erwartet, erhalten {:?}", other),
+        }
+    }
+
+    fn records_error(rows: Vec<Vec<String>>) -> String {
+        match records(rows) {
+            Err(Exception::Builtin { kind: "CSVError", message }) => message,
+            other => panic!("CSVError erwartet, erhalten {:?}", other),
+        }
+    }
+
+    fn row(fields: &[&str]) -> Vec<String> {
+        fields.iter().map(|field| field.to_string()).collect()
+    }
+
+    #[test]
+    fn parses_plain_and_quoted_fields() {
+        let rows = parse("a,b,c\r\n1,\"x, y\",\"sagt \"\"hallo\"\"\"\n\n2,\"zwei\nZeilen\",\n");
+        assert_eq!(
+            rows,
+            [
+                row(&["a", "b", "c"]),
+                row(&["1", "x, y", "sagt \"hallo\""]),
+                row(&["2", "zwei\nZeilen", ""]),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_quoted_fields_are_kept() {
+        assert_eq!(parse("\"\"\n"), [row(&[""])]);
+        assert_eq!(parse("a,"), [row(&["a", ""])]);
+        assert!(parse("").is_empty());
+    }
+
+    #[test]
+    fn other_delimiters() {
+        assert_eq!(dialect(';').parse("a;\"b;c\"\n").unwrap(), [row(&["a", "b;c"])]);
+    }
+
+    #[test]
+    fn bad_quoting_is_an_error() {
+        assert_eq!(parse_error("a\n\"b"), "Zeile 2: Anführungszeichen wird nicht geschlossen");
+        assert_eq!(
+            parse_error("\"a\"b"),
+            "Zeile 1: ',' nach schließendem Anführungszeichen erwartet, gefunden 'b'"
+        );
+    }
+
+    #[test]
+    fn quotes_only_where_needed() {
+        let dialect = dialect(',');
+        assert_eq!(dialect.quote("plain"), "plain");
+        assert_eq!(dialect.quote("a,b"), "\"a,b\"");
+        assert_eq!(dialect.quote("sagt \"hi\""), "\"sagt \"\"hi\"\"\"");
+        assert_eq!(dialect.quote("zwei\nZeilen"), "\"zwei\nZeilen\"");
+    }
+
+    #[test]
+    fn records_map_the_header() {
+        let records = records(vec![row(&["a", "b"]), row(&["1"]), row(&["2", "3"])]).unwrap();
+        let Value::List(records) = records else {
+            panic!("Liste erwartet");
+        };
+        let records = records.borrow();
+        assert_eq!(records.len(), 2);
+        let Value::Dict(first) = &records[0] else {
+            panic!("Dict erwartet");
+        };
+        let first = first.borrow();
+        assert_eq!(first.get(&Value::String("b".to_string())).unwrap(), Some(&Value::Null));
+        let error = records_error(vec![row(&["a"]), row(&["1", "2"])]);
+        assert_eq!(error, "Datensatz 1 hat 2 Felder, die Kopfzeile nur 1");
+    }
+}
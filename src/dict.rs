@@ -0,0 +1,134 @@
+// src/dict.rs
+
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::{type_name, Value};
+use std::collections::HashMap;
+use std::rc::Rc;
+
+// Hashbare Darstellung eines Schlüssels; nur unveränderliche Werte sind erlaubt
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+enum HashKey {
+    Number(u64),
+    String(String),
+    Boolean(bool),
+    Null,
+    Tuple(Vec<HashKey>),
+    Identity(usize), // Klassen und Objekte werden über ihre Adresse gehasht
+}
+
+impl HashKey {
+    fn from_value(value: &Value) -> Result<HashKey> {
+        let key = match value {
+            // 0.0 und -0.0 sind gleich und müssen denselben Schlüssel ergeben
+            Value::Number(n) if *n == 0.0 => HashKey::Number(0),
+            Value::Number(n) => HashKey::Number(n.to_bits()),
+            Value::String(s) => HashKey::String(s.clone()),
+            Value::Boolean(b) => HashKey::Boolean(*b),
+            Value::Null => HashKey::Null,
+            Value::Tuple(items) => {
+                HashKey::Tuple(items.iter().map(HashKey::from_value).collect::<Result<_>>()?)
+            }
+            Value::Class(class) => HashKey::Identity(Rc::as_ptr(class) as *const () as usize),
+            Value::Instance(instance) => HashKey::Identity(Rc::as_ptr(instance) as *const () as usize),
+            _ => {
+                return Err(Exception::type_error(format!(
+                    "Nicht hashbarer Typ: '{}'",
+                    type_name(value)
+                )))
+            }
+        };
+        Ok(key)
+    }
+}
+
+// Abbildung, die die Einfügereihenfolge erhält; Grundlage für Dicts und Sets
+#[derive(Clone, Debug)]
+pub struct OrderedMap<V> {
+    entries: Vec<(Value, V)>,
+    index: HashMap<HashKey, usize>,
+}
+
+pub type Dict = OrderedMap<Value>;
+pub type Set = OrderedMap<()>;
+
+impl<V> OrderedMap<V> {
+    pub fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+            index: HashMap::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    // Schlüsselzugriffe schlagen nur fehl, wenn der Schlüssel nicht hashbar ist
+    pub fn get(&self, key: &Value) -> Result<Option<&V>> {
+        let pos = self.index.get(&HashKey::from_value(key)?);
+        Ok(pos.map(|&pos| &self.entries[pos].1))
+    }
+
+    pub fn contains_key(&self, key: &Value) -> Result<bool> {
+        Ok(self.index.contains_key(&HashKey::from_value(key)?))
+    }
+
+    // Ein vorhandener Schlüssel behält seine Position, nur der Wert wird ersetzt
+    pub fn insert(&mut self, key: Value, value: V) -> Result<Option<V>> {
+        let hash_key = HashKey::from_value(&key)?;
+        match self.index.get(&hash_key) {
+            Some(&pos) => Ok(Some(std::mem::replace(&mut self.entries[pos].1, value))),
+            None => {
+                self.index.insert(hash_key, self.entries.len());
+                self.entries.push((key, value));
+                Ok(None)
+            }
+        }
+    }
+
+    pub fn remove(&mut self, key: &Value) -> Result<Option<V>> {
+        let Some(pos) = self.index.remove(&HashKey::from_value(key)?) else {
+            return Ok(None);
+        };
+        let (_, value) = self.entries.remove(pos);
+        for index in self.index.values_mut() {
+            if *index > pos {
+                *index -= 1;
+            }
+        }
+        Ok(Some(value))
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.index.clear();
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Value> {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&Value, &V)> {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+}
+
+impl<V> Default for OrderedMap<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Gleichheit ist unabhängig von der Reihenfolge
+impl<V: PartialEq> PartialEq for OrderedMap<V> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len()
+            && self
+                .iter()
+                .all(|(key, value)| matches!(other.get(key), Ok(Some(v)) if v == value))
+    }
+}
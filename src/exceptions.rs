@@ -0,0 +1,166 @@
+// src/exceptions.rs
+
+use crate::interpreter::{Interpreter, Value};
+use crate::token::Span;
+
+// Ein Laufzeitfehler. Interne Fehler tragen nur den Namen ihrer Ausnahmeklasse und eine
+// Meldung; erst wenn ein `except` sie abfängt, erzeugt der Interpreter daraus ein Objekt.
+#[derive(Debug)]
+pub enum Exception {
+    Builtin { kind: &'static str, message: String },
+    Raised(Value),
+}
+
+pub type Result<T> = std::result::Result<T, Exception>;
+
+impl Exception {
+    pub fn new(kind: &'static str, message: impl Into<String>) -> Self {
+        Exception::Builtin {
+            kind,
+            message: message.into(),
+        }
+    }
+
+    pub fn type_error(message: impl Into<String>) -> Self {
+        Exception::new("TypeError", message)
+    }
+
+    pub fn value_error(message: impl Into<String>) -> Self {
+        Exception::new("ValueError", message)
+    }
+
+    pub fn index_error(message: impl Into<String>) -> Self {
+        Exception::new("IndexError", message)
+    }
+
+    pub fn key_error(message: impl Into<String>) -> Self {
+        Exception::new("KeyError", message)
+    }
+
+    pub fn attribute_error(message: impl Into<String>) -> Self {
+        Exception::new("AttributeError", message)
+    }
+
+    pub fn zero_division() -> Self {
+        Exception::new("ZeroDivisionError", "Division durch Null")
+    }
+
+    pub fn memory_error() -> Self {
+        Exception::new("MemoryError", "Nicht genügend Speicher")
+    }
+}
+
+// Die eingebauten Ausnahmeklassen mit ihrer Basisklasse; Basisklassen stehen vorne
+pub const BUILTIN_EXCEPTIONS: &[(&str, Option<&str>)] = &[
+    ("BaseException", None),
+    ("Exception", Some("BaseException")),
+    ("ArithmeticError", Some("Exception")),
+    ("ZeroDivisionError", Some("ArithmeticError")),
+    ("OverflowError", Some("ArithmeticError")),
+    ("LookupError", Some("Exception")),
+    ("IndexError", Some("LookupError")),
+    ("KeyError", Some("LookupError")),
+    ("TypeError", Some("Exception")),
+    ("ValueError", Some("Exception")),
+    ("NameError", Some("Exception")),
+    ("AttributeError", Some("Exception")),
+    ("RuntimeError", Some("Exception")),
+    ("MemoryError", Some("Exception")),
+    ("AssertionError", Some("Exception")),
+    ("ImportError", Some("Exception")),
+    ("OSError", Some("Exception")),
+    ("FileNotFoundError", Some("OSError")),
+    ("FileExistsError", Some("OSError")),
+    ("PermissionError", Some("OSError")),
+    ("IsADirectoryError", Some("OSError")),
+    ("NotADirectoryError", Some("OSError")),
+    ("ModuleNotFoundError", Some("ImportError")),
+];
+
+// Ausnahmeklassen eingebauter Module mit ihrer Basisklasse. Sie sind keine globalen Namen,
+// sondern nur über ihr Modul erreichbar (siehe `modules::native_exceptions`).
+pub const MODULE_EXCEPTIONS: &[(&str, &str)] = &[
+    ("JSONDecodeError", "ValueError"),
+    ("CSVError", "Exception"),
+];
+
+// Eine ausgeführte Quelldatei; ihre Zeilen erscheinen im Traceback
+pub struct SourceFile {
+    pub path: String,
+    lines: Vec<String>,
+}
+
+impl SourceFile {
+    pub fn new(path: &str, code: &str) -> Self {
+        SourceFile {
+            path: path.to_string(),
+            lines: code.lines().map(String::from).collect(),
+        }
+    }
+
+    fn line(&self, number: usize) -> Option<&str> {
+        self.lines.get(number.checked_sub(1)?).map(|line| line.trim())
+    }
+
+    // Der Quelltext im Bereich `span`, über mehrere Zeilen hinweg
+    pub fn text(&self, span: Span) -> Option<String> {
+        let mut text = String::new();
+        for number in span.line..=span.end_line {
+            let line = self.lines.get(number.checked_sub(1)?)?;
+            let from = if number == span.line { span.column - 1 } else { 0 };
+            let to = if number == span.end_line { span.end_column - 1 } else { usize::MAX };
+            if number > span.line {
+                text.push('\n');
+            }
+            text.extend(line.chars().skip(from).take(to.saturating_sub(from)));
+        }
+        Some(text)
+    }
+}
+
+// Ein Eintrag im Traceback: die Funktion und die Stelle, die sie gerade ausführte
+pub struct TraceEntry {
+    pub function: String,
+    pub span: Span,
+}
+
+// Äußerster Aufruf zuerst, wie in Python
+pub fn format_traceback(sources: &[SourceFile], entries: &[TraceEntry]) -> String {
+    let mut result = String::from("Traceback (jüngster Aufruf zuletzt):\n");
+    for entry in entries {
+        let source = sources.get(entry.span.file);
+        let path = source.map_or("<unbekannt>", |source| source.path.as_str());
+        result.push_str(&format!(
+            "  Datei \"{}\", Zeile {}, in {}\n",
+            path, entry.span.line, entry.function
+        ));
+        if let Some(line) = source.and_then(|source| source.line(entry.span.line)) {
+            result.push_str(&format!("    {}\n", line));
+        }
+    }
+    result
+}
+
+// Native Methoden von `BaseException`; sie speichern und zeigen die Argumente in `args`
+pub const NATIVE_METHODS: &[&str] = &["BaseException.__init__", "BaseException.__str__"];
+
+pub fn call_native_method(interpreter: &mut Interpreter, name: &str, args: Vec<Value>) -> Result<Value> {
+    let mut args = args.into_iter();
+    let Some(receiver) = args.next() else {
+        return Err(Exception::type_error(format!("{}() erwartet ein Objekt", name)));
+    };
+    match name {
+        "BaseException.__init__" => {
+            interpreter.set_attribute(&receiver, "args", Value::Tuple(args.collect()))?;
+            Ok(Value::Null)
+        }
+        _ => {
+            let message = match interpreter.get_attribute(receiver, "args")? {
+                Value::Tuple(items) if items.is_empty() => String::new(),
+                Value::Tuple(items) if items.len() == 1 => interpreter.value_to_string(&items[0])?,
+                other => interpreter.value_to_string(&other)?,
+            };
+            Ok(Value::String(message))
+        }
+    }
+}
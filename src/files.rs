@@ -0,0 +1,311 @@
+// src/files.rs
+
+use crate::builtins::reject_kwargs;
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::{type_name, Value};
+use crate::modules::{expect_args, integer};
+use std::cell::RefCell;
+use std::fmt;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::rc::Rc;
+
+pub const FUNCTIONS: &[&str] = &["open", "exists", "listdir", "mkdir", "remove"];
+
+pub const FILE_METHODS: &[&str] = &[
+    "read", "readline", "readlines", "write", "close", "__enter__", "__exit__",
+];
+
+// Eine geöffnete Textdatei. Zum Lesen wird der Inhalt beim Öffnen ganz geladen,
+// geschrieben wird gepuffert bis `close()`.
+pub struct FileHandle {
+    pub path: String,
+    pub mode: String,
+    state: RefCell<FileState>,
+}
+
+enum FileState {
+    Reading { content: Vec<char>, position: usize },
+    Writing(BufWriter<File>),
+    Closed,
+}
+
+impl fmt::Debug for FileHandle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<file '{}' mode '{}'>", self.path, self.mode)
+    }
+}
+
+// Dateien sind nur mit sich selbst gleich
+impl PartialEq for FileHandle {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl FileHandle {
+    pub fn open(path: &str, mode: &str) -> Result<FileHandle> {
+        let state = match mode {
+            "r" => {
+                let content = fs::read_to_string(path).map_err(|error| os_error(error, path))?;
+                FileState::Reading {
+                    content: content.chars().collect(),
+                    position: 0,
+                }
+            }
+            "w" | "a" | "x" => {
+                let mut options = OpenOptions::new();
+                match mode {
+                    "w" => options.write(true).create(true).truncate(true),
+                    "a" => options.append(true).create(true),
+                    _ => options.write(true).create_new(true),
+                };
+                let file = options.open(path).map_err(|error| os_error(error, path))?;
+                FileState::Writing(BufWriter::new(file))
+            }
+            _ => {
+                return Err(Exception::value_error(format!(
+                    "Ungültiger Dateimodus '{}'; erlaubt sind 'r', 'w', 'a' und 'x'",
+                    mode
+                )))
+            }
+        };
+        Ok(FileHandle {
+            path: path.to_string(),
+            mode: mode.to_string(),
+            state: RefCell::new(state),
+        })
+    }
+
+    pub fn close(&self) -> Result<()> {
+        let state = std::mem::replace(&mut *self.state.borrow_mut(), FileState::Closed);
+        if let FileState::Writing(mut writer) = state {
+            writer.flush().map_err(|error| os_error(error, &self.path))?;
+        }
+        Ok(())
+    }
+
+    // Die restlichen Zeilen, jeweils mit ihrem Zeilenende
+    pub fn lines(&self) -> Result<Vec<Value>> {
+        let mut lines = Vec::new();
+        loop {
+            let line = self.readline(None)?;
+            if line.is_empty() {
+                return Ok(lines);
+            }
+            lines.push(Value::String(line));
+        }
+    }
+
+    fn read(&self, limit: Option<usize>) -> Result<String> {
+        let mut state = self.state.borrow_mut();
+        let (content, position) = self.reader(&mut state)?;
+        let end = limit.map_or(content.len(), |limit| (*position + limit).min(content.len()));
+        let text = content[*position..end].iter().collect();
+        *position = end;
+        Ok(text)
+    }
+
+    fn readline(&self, limit: Option<usize>) -> Result<String> {
+        let mut state = self.state.borrow_mut();
+        let (content, position) = self.reader(&mut state)?;
+        let rest = &content[*position..];
+        let mut end = rest.iter().position(|&c| c == '\n').map_or(rest.len(), |pos| pos + 1);
+        if let Some(limit) = limit {
+            end = end.min(limit);
+        }
+        let line = rest[..end].iter().collect();
+        *position += end;
+        Ok(line)
+    }
+
+    fn write(&self, text: &str) -> Result<usize> {
+        match &mut *self.state.borrow_mut() {
+            FileState::Writing(writer) => {
+                writer
+                    .write_all(text.as_bytes())
+                    .map_err(|error| os_error(error, &self.path))?;
+                Ok(text.chars().count())
+            }
+            FileState::Closed => Err(closed_file()),
+            FileState::Reading { .. } => Err(Exception::new(
+                "OSError",
+                format!("Datei '{}' ist nicht zum Schreiben geöffnet", self.path),
+            )),
+        }
+    }
+
+    fn reader<'a>(&self, state: &'a mut FileState) -> Result<(&'a Vec<char>, &'a mut usize)> {
+        match state {
+            FileState::Reading { content, position } => Ok((content, position)),
+            FileState::Closed => Err(closed_file()),
+            FileState::Writing(_) => Err(Exception::new(
+                "OSError",
+                format!("Datei '{}' ist nicht zum Lesen geöffnet", self.path),
+            )),
+        }
+    }
+}
+
+pub fn call_file_method(
+    file: &Rc<FileHandle>,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    let method = format!("file.{}", name);
+    reject_kwargs(&method, &kwargs)?;
+    match name {
+        "read" | "readline" => {
+            expect_args(&method, &args, 0, 1)?;
+            // Eine negative Anzahl liest wie in Python alles
+            let limit = match args.first() {
+                None | Some(Value::Null) => None,
+                Some(size) => usize::try_from(integer(&method, size)?).ok(),
+            };
+            let text = if name == "read" {
+                file.read(limit)?
+            } else {
+                file.readline(limit)?
+            };
+            Ok(Value::String(text))
+        }
+        "readlines" => {
+            expect_args(&method, &args, 0, 0)?;
+            Ok(Value::list(file.lines()?))
+        }
+        "write" => {
+            expect_args(&method, &args, 1, 1)?;
+            let Value::String(text) = &args[0] else {
+                return Err(Exception::type_error(format!(
+                    "file.write() erwartet 'str', nicht '{}'",
+                    type_name(&args[0])
+                )));
+            };
+            Ok(Value::Number(file.write(text)? as f64))
+        }
+        "close" => {
+            expect_args(&method, &args, 0, 0)?;
+            file.close()?;
+            Ok(Value::Null)
+        }
+        // Kontextmanager: `with` schließt die Datei am Ende des Blocks
+        "__enter__" => {
+            expect_args(&method, &args, 0, 0)?;
+            Ok(Value::File(Rc::clone(file)))
+        }
+        "__exit__" => {
+            expect_args(&method, &args, 3, 3)?;
+            file.close()?;
+            Ok(Value::Boolean(false))
+        }
+        _ => Err(Exception::attribute_error(format!(
+            "'file' hat keine Methode '{}'",
+            name
+        ))),
+    }
+}
+
+pub fn call(name: &str, args: Vec<Value>, kwargs: Vec<(String, Value)>) -> Result<Value> {
+    let method = format!("fs.{}", name);
+    if name == "open" {
+        return open(&method, args, kwargs);
+    }
+    reject_kwargs(&method, &kwargs)?;
+    match name {
+        "exists" => {
+            expect_args(&method, &args, 1, 1)?;
+            let path = path_argument(&method, &args[0])?;
+            Ok(Value::Boolean(fs::metadata(path).is_ok()))
+        }
+        "listdir" => {
+            expect_args(&method, &args, 0, 1)?;
+            let path = match args.first() {
+                Some(path) => path_argument(&method, path)?,
+                None => ".",
+            };
+            let mut names = Vec::new();
+            for entry in fs::read_dir(path).map_err(|error| os_error(error, path))? {
+                let entry = entry.map_err(|error| os_error(error, path))?;
+                names.push(entry.file_name().to_string_lossy().into_owned());
+            }
+            // Die Reihenfolge des Betriebssystems ist zufällig; sortiert bleibt sie stabil
+            names.sort();
+            Ok(Value::list(names.into_iter().map(Value::String).collect()))
+        }
+        "mkdir" => {
+            expect_args(&method, &args, 1, 1)?;
+            let path = path_argument(&method, &args[0])?;
+            fs::create_dir(path).map_err(|error| os_error(error, path))?;
+            Ok(Value::Null)
+        }
+        "remove" => {
+            expect_args(&method, &args, 1, 1)?;
+            let path = path_argument(&method, &args[0])?;
+            fs::remove_file(path).map_err(|error| os_error(error, path))?;
+            Ok(Value::Null)
+        }
+        _ => Err(Exception::attribute_error(format!(
+            "Modul 'fs' hat kein Attribut '{}'",
+            name
+        ))),
+    }
+}
+
+// fs.open(path, mode="r")
+fn open(method: &str, args: Vec<Value>, kwargs: Vec<(String, Value)>) -> Result<Value> {
+    let mut args = args;
+    for (key, value) in kwargs {
+        match key.as_str() {
+            "mode" if args.len() == 1 => args.push(value),
+            _ => {
+                return Err(Exception::type_error(format!(
+                    "Unbekanntes Schlüsselwortargument '{}' für {}()",
+                    key, method
+                )))
+            }
+        }
+    }
+    expect_args(method, &args, 1, 2)?;
+    let path = path_argument(method, &args[0])?;
+    let mode = match args.get(1) {
+        Some(Value::String(mode)) => mode.as_str(),
+        Some(other) => {
+            return Err(Exception::type_error(format!(
+                "{}(): Modus muss 'str' sein, nicht '{}'",
+                method,
+                type_name(other)
+            )))
+        }
+        None => "r",
+    };
+    Ok(Value::File(Rc::new(FileHandle::open(path, mode)?)))
+}
+
+fn path_argument<'a>(method: &str, value: &'a Value) -> Result<&'a str> {
+    match value {
+        Value::String(path) => Ok(path),
+        other => Err(Exception::type_error(format!(
+            "{}() erwartet einen Pfad als 'str', nicht '{}'",
+            method,
+            type_name(other)
+        ))),
+    }
+}
+
+fn closed_file() -> Exception {
+    Exception::value_error("E/A-Operation auf geschlossener Datei")
+}
+
+// Ordnet Betriebssystemfehler den passenden Unterklassen von OSError zu
+pub fn os_error(error: io::Error, path: &str) -> Exception {
+    let kind = match error.kind() {
+        io::ErrorKind::NotFound => "FileNotFoundError",
+        io::ErrorKind::AlreadyExists => "FileExistsError",
+        io::ErrorKind::PermissionDenied => "PermissionError",
+        io::ErrorKind::IsADirectory => "IsADirectoryError",
+        io::ErrorKind::NotADirectory => "NotADirectoryError",
+        _ => "OSError",
+    };
+    Exception::new(kind, format!("{}: '{}'", error, path))
+}
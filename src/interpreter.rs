@@ -1,297 +1,3371 @@
 // src/interpreter.rs
 
-use crate::ast::ASTNode;
+use crate::ast::{ASTNode, ComprehensionClause, ExceptHandler};
+use crate::builtins;
+use crate::dict::{Dict, Set};
+use crate::exceptions::{self, Exception, Result, SourceFile, TraceEntry};
+use crate::files::FileHandle;
+use crate::methods;
+use crate::modules;
+use crate::operators;
+use crate::parser::Parser;
+use crate::random::Rng;
+use crate::threading::Lock;
+use crate::token::Span;
+use std::cell::RefCell;
+use std::cmp::Ordering;
 use std::collections::HashMap;
+use std::fmt;
+use std::fs;
+use std::path::PathBuf;
+use std::rc::Rc;
 
 pub struct Interpreter {
-    environment: Environment,
+    environment: Rc<RefCell<Environment>>,
+    frames: Vec<Frame>,
+    exception_classes: HashMap<String, Rc<Class>>,
+    module_exceptions: HashMap<String, Rc<Class>>, // Nur als Attribut ihres Moduls sichtbar
+    handling: Vec<Value>, // Ausnahmen, deren `except`-Zweig gerade läuft; für `raise` ohne Ausdruck
+    sources: Vec<SourceFile>,
+    traceback: Option<Vec<TraceEntry>>, // Aufrufstapel an der Stelle der noch nicht abgefangenen Ausnahme
+    assertions: bool,
+    modules: HashMap<PathBuf, Value>, // Bereits geladene Module, nach kanonischem Pfad
+    importing: Vec<PathBuf>,          // Module, deren Code gerade läuft; für zirkuläre Importe
+    pub(crate) rng: Rng,              // Zustand des Moduls `random`
+    printing: Vec<*const ()>,         // Listen und Dicts, deren Text gerade entsteht
+    comparing: Vec<(*const (), *const ())>, // Paare von Containern, die gerade verglichen werden
 }
 
-type Environment = HashMap<String, Value>;
+// Ein laufender Aufruf einer Funktion oder eines Lambdas; der unterste Rahmen ist die Modulebene
+struct Frame {
+    function: Value, // Null für die Modulebene
+    span: Span,      // Die Stelle, die der Rahmen gerade ausführt
+}
+
+// Ein Gültigkeitsbereich; Funktionen und Lambdas halten ihren umgebenden Bereich fest
+#[derive(Default)]
+pub struct Environment {
+    values: HashMap<String, Value>,
+    parent: Option<Rc<RefCell<Environment>>>,
+}
+
+impl Environment {
+    fn get(&self, name: &str) -> Option<Value> {
+        match self.values.get(name) {
+            Some(value) => Some(value.clone()),
+            None => self.parent.as_ref().and_then(|p| p.borrow().get(name)),
+        }
+    }
+
+    fn set(&mut self, name: String, value: Value) {
+        self.values.insert(name, value);
+    }
+}
+
+impl fmt::Debug for Environment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<environment>")
+    }
+}
+
+// Umgebungen sind nur mit sich selbst identisch
+impl PartialEq for Environment {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+// Ein aufgelöstes Zuweisungsziel, dessen Teilausdrücke bereits ausgewertet wurden
+enum Place {
+    Variable(String),
+    Index(Value, Value),
+    Attribute(Value, String),
+}
+
+// Zustand eines Generatorausdrucks: pro `for`-Teil ein laufender Iterator
+pub struct Generator {
+    element: ASTNode,
+    clauses: Vec<ComprehensionClause>,
+    scope: Rc<RefCell<Environment>>,
+    iterators: Vec<std::vec::IntoIter<Value>>,
+}
+
+impl fmt::Debug for Generator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("<generator>")
+    }
+}
+
+impl PartialEq for Generator {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+// Eine benutzerdefinierte Klasse; ihre Attribute sind die Namen aus dem Klassenrumpf
+pub struct Class {
+    pub name: String,
+    base: Option<Rc<Class>>,
+    attributes: RefCell<HashMap<String, Value>>,
+}
+
+impl Class {
+    // Sucht ein Attribut in der Klasse und danach entlang der Basisklassen
+    fn lookup(&self, name: &str) -> Option<Value> {
+        if let Some(value) = self.attributes.borrow().get(name) {
+            return Some(value.clone());
+        }
+        self.base.as_ref().and_then(|base| base.lookup(name))
+    }
+
+    pub(crate) fn is_subclass(&self, other: &Class) -> bool {
+        std::ptr::eq(self, other) || self.base.as_ref().is_some_and(|base| base.is_subclass(other))
+    }
+}
+
+// Ein Objekt einer Klasse mit eigenen Attributen
+pub struct Instance {
+    pub class: Rc<Class>,
+    fields: RefCell<HashMap<String, Value>>,
+}
+
+// Ein geladenes Modul; seine Attribute sind die globalen Namen der Moduldatei
+pub struct Module {
+    pub name: String,
+    globals: Rc<RefCell<Environment>>,
+}
+
+impl fmt::Debug for Module {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<module '{}'>", self.name)
+    }
+}
+
+impl PartialEq for Module {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl fmt::Debug for Class {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<class '{}'>", self.name)
+    }
+}
+
+impl fmt::Debug for Instance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "<{} object>", self.class.name)
+    }
+}
+
+// Klassen und Objekte sind nur mit sich selbst gleich
+impl PartialEq for Class {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
 
+impl PartialEq for Instance {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+#[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug, PartialEq)]
 pub enum Value {
     Number(f64),
     String(String),
     Boolean(bool),
-    List(Vec<Value>),
+    // Listen und Dicts sind Referenztypen: Zuweisung und Übergabe teilen dasselbe Objekt
+    List(Rc<RefCell<Vec<Value>>>),
     Tuple(Vec<Value>),   // Stelle sicher, dass dies vorhanden ist
+    Dict(Rc<RefCell<Dict>>),
+    Set(Set),
+    Generator(Rc<RefCell<Generator>>),
     Function {
+        name: String,
+        params: Vec<String>,
+        body: Box<ASTNode>,
+        closure: Rc<RefCell<Environment>>,
+    },
+    Lambda {
         params: Vec<String>,
         body: Box<ASTNode>,
+        closure: Rc<RefCell<Environment>>,
+    },
+    Builtin(String),
+    Class(Rc<Class>),
+    Instance(Rc<Instance>),
+    Module(Rc<Module>),
+    File(Rc<FileHandle>),
+    Lock(Rc<Lock>),
+    // Eine an ihr Objekt gebundene benutzerdefinierte Methode
+    Method {
+        receiver: Box<Value>,
+        function: Box<Value>,
+    },
+    // Ergebnis von `super()`: sucht Attribute erst ab der Basisklasse von `class`
+    Super {
+        class: Rc<Class>,
+        receiver: Box<Value>,
+    },
+    BoundMethod {
+        receiver: Box<Value>,
+        name: String,
     },
     ReturnValue(Box<Value>),
     Null,
 }
 
+impl Value {
+    pub fn list(items: Vec<Value>) -> Value {
+        Value::List(Rc::new(RefCell::new(items)))
+    }
+
+    pub fn dict(dict: Dict) -> Value {
+        Value::Dict(Rc::new(RefCell::new(dict)))
+    }
+}
 
 impl Interpreter {
     pub fn new() -> Self {
+        // Eingebaute Ausnahmeklassen; nur die Wurzel hat native Methoden, die anderen erben sie
+        let mut exception_classes: HashMap<String, Rc<Class>> = HashMap::new();
+        for (name, base) in exceptions::BUILTIN_EXCEPTIONS {
+            let mut attributes = HashMap::new();
+            if base.is_none() {
+                for method in exceptions::NATIVE_METHODS {
+                    let (_, method_name) = method.split_once('.').unwrap();
+                    attributes.insert(method_name.to_string(), Value::Builtin(method.to_string()));
+                }
+            }
+            let class = Class {
+                name: name.to_string(),
+                base: base.map(|base| Rc::clone(&exception_classes[base])),
+                attributes: RefCell::new(attributes),
+            };
+            exception_classes.insert(name.to_string(), Rc::new(class));
+        }
+        let module_exceptions = exceptions::MODULE_EXCEPTIONS
+            .iter()
+            .map(|(name, base)| {
+                let class = Class {
+                    name: name.to_string(),
+                    base: Some(Rc::clone(&exception_classes[*base])),
+                    attributes: RefCell::new(HashMap::new()),
+                };
+                (name.to_string(), Rc::new(class))
+            })
+            .collect();
+        let mut globals = Environment::default();
+        globals.set("__name__".to_string(), Value::String("__main__".to_string()));
         Self {
-            environment: HashMap::new(),
+            environment: Rc::new(RefCell::new(globals)),
+            frames: vec![Frame {
+                function: Value::Null,
+                span: Span::default(),
+            }],
+            exception_classes,
+            module_exceptions,
+            handling: Vec::new(),
+            sources: Vec::new(),
+            traceback: None,
+            assertions: true,
+            modules: HashMap::new(),
+            importing: Vec::new(),
+            rng: Rng::from_time(),
+            printing: Vec::new(),
+            comparing: Vec::new(),
         }
     }
 
-    pub fn interpret(&mut self, node: &ASTNode) -> Value {
+    // `assert`-Anweisungen werden dann übersprungen, ohne ihre Bedingung auszuwerten
+    pub fn disable_assertions(&mut self) {
+        self.assertions = false;
+    }
+
+    // Meldet eine Quelldatei an; der Index wird dem Lexer für die Positionen der Tokens übergeben
+    pub fn add_source(&mut self, path: &str, code: &str) -> usize {
+        self.sources.push(SourceFile::new(path, code));
+        self.sources.len() - 1
+    }
+
+    pub fn interpret(&mut self, node: &ASTNode) -> Result<Value> {
         match node {
             ASTNode::Program(statements) => {
                 let mut result = Value::Null;
                 for stmt in statements {
-                    result = self.interpret(stmt);
+                    result = self.interpret(stmt)?;
                     if let Value::ReturnValue(_) = result {
-                        return result;
+                        return Ok(result);
                     }
                 }
-                result
+                Ok(result)
             }
             ASTNode::Block(statements) => {
-                let local_env = self.environment.clone();
                 let mut result = Value::Null;
                 for stmt in statements {
-                    result = self.interpret(stmt);
+                    result = self.interpret(stmt)?;
                     if let Value::ReturnValue(_) = result {
-                        return result;
+                        return Ok(result);
                     }
                 }
-                self.environment = local_env;
+                Ok(result)
+            }
+            ASTNode::Statement { span, node } => {
+                self.set_position(*span);
+                let result = self.interpret(node);
+                // Der Stapel wird dort festgehalten, wo die Ausnahme zuerst auftritt
+                if result.is_err() && self.traceback.is_none() {
+                    self.traceback = Some(self.capture_traceback());
+                }
                 result
             }
-            ASTNode::Statement(expr) => self.interpret(expr),
-            ASTNode::Number(value) => Value::Number(*value),
-            ASTNode::String(value) => Value::String(value.clone()),
-            ASTNode::Boolean(value) => Value::Boolean(*value),
-            ASTNode::Variable(name) => self.environment.get(name).cloned().unwrap_or(Value::Null),
-            ASTNode::Assignment { name, value } => {
-                let val = self.interpret(value);
-                self.environment.insert(name.clone(), val.clone());
-                val
+            ASTNode::Number(value) => Ok(Value::Number(*value)),
+            ASTNode::String(value) => Ok(Value::String(value.clone())),
+            ASTNode::Boolean(value) => Ok(Value::Boolean(*value)),
+            ASTNode::Null => Ok(Value::Null),
+            ASTNode::Variable(name) => self.lookup_variable(name),
+            ASTNode::Assignment { targets, value } => {
+                let val = self.interpret(value)?;
+                for target in targets {
+                    self.assign(target, val.clone())?;
+                }
+                Ok(val)
+            }
+            ASTNode::AugAssignment { target, operator, value } => {
+                let place = self.resolve_place(target)?;
+                let current = self.load_place(&place)?;
+                let operand = self.interpret(value)?;
+                // `xs += ys` erweitert die Liste an Ort und Stelle
+                if let (Value::List(items), "+") = (&current, operator.as_str()) {
+                    let extra = self.iterate(&operand)?;
+                    items.borrow_mut().extend(extra);
+                    return Ok(current);
+                }
+                let result = self.evaluate_binary_op(&current, operator, &operand)?;
+                self.store_place(&place, result.clone())?;
+                Ok(result)
             }
             ASTNode::BinaryOp { left, operator, right } => {
-                let left_val = self.interpret(left);
-                let right_val = self.interpret(right);
+                let left_val = self.interpret(left)?;
+                let right_val = self.interpret(right)?;
                 self.evaluate_binary_op(&left_val, operator, &right_val)
             }
+            ASTNode::Comparison { left, comparisons } => {
+                // Jeder Operand wird höchstens einmal ausgewertet
+                let mut left_val = self.interpret(left)?;
+                for (operator, right) in comparisons {
+                    let right_val = self.interpret(right)?;
+                    let result = self.evaluate_binary_op(&left_val, operator, &right_val)?;
+                    if !self.is_truthy(&result)? {
+                        return Ok(Value::Boolean(false));
+                    }
+                    left_val = right_val;
+                }
+                Ok(Value::Boolean(true))
+            }
             ASTNode::UnaryOp { operator, operand } => {
-                let val = self.interpret(operand);
+                let val = self.interpret(operand)?;
                 self.evaluate_unary_op(operator, &val)
             }
             ASTNode::IfStatement { condition, then_branch, else_branch } => {
-                let cond_value = self.interpret(condition);
-                if self.is_truthy(&cond_value) {
+                let cond_value = self.interpret(condition)?;
+                if self.is_truthy(&cond_value)? {
                     self.interpret(then_branch)
                 } else if let Some(else_node) = else_branch {
                     self.interpret(else_node)
                 } else {
-                    Value::Null
+                    Ok(Value::Null)
+                }
+            }
+            ASTNode::ConditionalExpression { condition, then_expr, else_expr } => {
+                let cond_value = self.interpret(condition)?;
+                if self.is_truthy(&cond_value)? {
+                    self.interpret(then_expr)
+                } else {
+                    self.interpret(else_expr)
                 }
             }
             ASTNode::WhileLoop { condition, body } => {
                 let mut result = Value::Null;
                 loop {
-                    let cond_value = self.interpret(condition);
-                    if !self.is_truthy(&cond_value) {
+                    let cond_value = self.interpret(condition)?;
+                    if !self.is_truthy(&cond_value)? {
                         break;
                     }
-                    result = self.interpret(body);
+                    result = self.interpret(body)?;
                     if let Value::ReturnValue(_) = result {
-                        return result;
+                        return Ok(result);
                     }
                 }
-                result
+                Ok(result)
+            }
+            ASTNode::TryStatement { body, handlers, else_branch, finally_branch } => {
+                let mut outcome = match self.interpret(body) {
+                    Err(exception) => {
+                        let exception = self.exception_object(exception);
+                        self.handle_exception(exception, handlers)
+                    }
+                    Ok(Value::ReturnValue(value)) => Ok(Value::ReturnValue(value)),
+                    Ok(result) => match else_branch {
+                        Some(else_branch) => self.interpret(else_branch),
+                        None => Ok(result),
+                    },
+                };
+                // `finally` läuft immer; ein eigenes `return` oder ein Fehler darin gewinnt
+                if let Some(finally_branch) = finally_branch {
+                    let pending = self.traceback.take();
+                    let finally_result = self.interpret(finally_branch)?;
+                    self.traceback = pending;
+                    if let Value::ReturnValue(_) = finally_result {
+                        outcome = Ok(finally_result);
+                    }
+                }
+                outcome
+            }
+            ASTNode::Import(modules) => {
+                for (name, alias) in modules {
+                    let module = self.import_module(name)?;
+                    let binding = alias.clone().unwrap_or_else(|| name.clone());
+                    self.environment.borrow_mut().set(binding, module);
+                }
+                Ok(Value::Null)
+            }
+            ASTNode::FromImport { module: module_name, names } => {
+                let module = self.import_module(module_name)?;
+                for (name, alias) in names {
+                    let value = self.get_attribute(module.clone(), name).map_err(|_| {
+                        Exception::new(
+                            "ImportError",
+                            format!("'{}' kann nicht aus '{}' importiert werden", name, module_name),
+                        )
+                    })?;
+                    let binding = alias.clone().unwrap_or_else(|| name.clone());
+                    self.environment.borrow_mut().set(binding, value);
+                }
+                Ok(Value::Null)
+            }
+            // `__exit__` läuft immer; bei einem Fehler erhält es Klasse und Objekt der Ausnahme
+            // und kann sie mit einem wahren Rückgabewert unterdrücken
+            ASTNode::With { context, name, body } => {
+                let manager = self.interpret(context)?;
+                let enter = self.context_method(&manager, "__enter__")?;
+                let exit = self.context_method(&manager, "__exit__")?;
+                let value = self.call_function(&enter, Vec::new(), Vec::new())?;
+                if let Some(name) = name {
+                    self.environment.borrow_mut().set(name.clone(), value);
+                }
+                match self.interpret(body) {
+                    Ok(result) => {
+                        let no_exception = vec![Value::Null, Value::Null, Value::Null];
+                        self.call_function(&exit, no_exception, Vec::new())?;
+                        Ok(result)
+                    }
+                    Err(exception) => {
+                        let exception = self.exception_object(exception);
+                        let class = match &exception {
+                            Value::Instance(instance) => Value::Class(Rc::clone(&instance.class)),
+                            _ => Value::Null,
+                        };
+                        let pending = self.traceback.take();
+                        let details = vec![class, exception.clone(), Value::Null];
+                        let suppress = self.call_function(&exit, details, Vec::new())?;
+                        if self.is_truthy(&suppress)? {
+                            return Ok(Value::Null);
+                        }
+                        self.traceback = pending;
+                        Err(Exception::Raised(exception))
+                    }
+                }
+            }
+            ASTNode::Assert { condition, message, span } => {
+                if !self.assertions {
+                    return Ok(Value::Null);
+                }
+                let value = self.interpret(condition)?;
+                if self.is_truthy(&value)? {
+                    return Ok(Value::Null);
+                }
+                match message {
+                    Some(message) => {
+                        let message = self.interpret(message)?;
+                        let class = Rc::clone(&self.exception_classes["AssertionError"]);
+                        Err(Exception::Raised(self.instantiate(&class, vec![message], Vec::new())?))
+                    }
+                    // Ohne Meldung zeigt der Fehler die fehlgeschlagene Bedingung
+                    None => {
+                        let source = self.sources.get(span.file).and_then(|source| source.text(*span));
+                        Err(Exception::new("AssertionError", source.unwrap_or_default()))
+                    }
+                }
+            }
+            ASTNode::Raise(expr) => {
+                let exception = match expr {
+                    Some(expr) => {
+                        let value = self.interpret(expr)?;
+                        self.make_exception(value)?
+                    }
+                    None => self.handling.last().cloned().ok_or_else(|| {
+                        Exception::new("RuntimeError", "Keine aktive Ausnahme zum erneuten Auslösen")
+                    })?,
+                };
+                Err(Exception::Raised(exception))
+            }
+            ASTNode::ClassDef { name, base, body } => {
+                let base = match base {
+                    Some(base) => match self.interpret(base)? {
+                        Value::Class(class) => Some(class),
+                        other => {
+                            return Err(Exception::type_error(format!(
+                                "Basisklasse von '{}' muss eine Klasse sein, nicht '{}'",
+                                name,
+                                type_name(&other)
+                            )))
+                        }
+                    },
+                    None => None,
+                };
+                let scope = Rc::new(RefCell::new(Environment {
+                    values: HashMap::new(),
+                    parent: Some(Rc::clone(&self.environment)),
+                }));
+                let outer = std::mem::replace(&mut self.environment, Rc::clone(&scope));
+                let result = self.interpret(body);
+                self.environment = outer;
+                result?;
+                // Die Namen des Rumpfs werden zu Klassenattributen. Der geleerte Bereich bleibt
+                // Closure der Methoden, sie sehen also wie in Python nur den umgebenden Bereich
+                // und `__class__`, über das `super()` die Klasse findet.
+                let attributes = std::mem::take(&mut scope.borrow_mut().values);
+                let class = Value::Class(Rc::new(Class {
+                    name: name.clone(),
+                    base,
+                    attributes: RefCell::new(attributes),
+                }));
+                scope.borrow_mut().set("__class__".to_string(), class.clone());
+                self.environment.borrow_mut().set(name.clone(), class.clone());
+                Ok(class)
             }
             ASTNode::FunctionDef { name, params, body } => {
                 let func = Value::Function {
+                    name: name.clone(),
                     params: params.clone(),
                     body: Box::new(*body.clone()),
+                    closure: Rc::clone(&self.environment),
                 };
-                self.environment.insert(name.clone(), func.clone());
-                func
-            }
-            ASTNode::FunctionCall { name, args } => {
-                let func = self.environment.get(name).cloned();
-                if let Some(Value::Function { params, body }) = func {
-                    if params.len() != args.len() {
-                        panic!("Falsche Anzahl von Argumenten für Funktion '{}'", name);
-                    }
-                    let mut new_env = self.environment.clone();
-                    for (param, arg) in params.iter().zip(args) {
-                        let val = self.interpret(arg);
-                        new_env.insert(param.clone(), val);
-                    }
-                    let mut interpreter = Interpreter {
-                        environment: new_env,
-                    };
-                    let result = interpreter.interpret(&body);
-                    if let Value::ReturnValue(val) = result {
-                        *val
-                    } else {
-                        Value::Null
-                    }
-                } else {
-                    panic!("Funktion '{}' nicht definiert", name);
+                self.environment.borrow_mut().set(name.clone(), func.clone());
+                Ok(func)
+            }
+            ASTNode::Lambda { params, body } => Ok(Value::Lambda {
+                params: params.clone(),
+                body: body.clone(),
+                closure: Rc::clone(&self.environment),
+            }),
+            ASTNode::FunctionCall { callee, args, kwargs, span } => {
+                let func = self.interpret(callee)?;
+                let mut arg_values = Vec::new();
+                for arg in args {
+                    arg_values.push(self.interpret(arg)?);
                 }
+                let mut kwarg_values = Vec::new();
+                for (name, arg) in kwargs {
+                    kwarg_values.push((name.clone(), self.interpret(arg)?));
+                }
+                self.set_position(*span);
+                self.call_function(&func, arg_values, kwarg_values)
+            }
+            ASTNode::Attribute { object, name } => {
+                let object_val = self.interpret(object)?;
+                self.get_attribute(object_val, name)
+            }
+            ASTNode::Index { object, index } => {
+                let object_val = self.interpret(object)?;
+                let index_val = self.interpret(index)?;
+                self.evaluate_index(&object_val, &index_val)
             }
             ASTNode::Return(expr) => {
-                let val = self.interpret(expr);
-                Value::ReturnValue(Box::new(val))
+                let val = self.interpret(expr)?;
+                Ok(Value::ReturnValue(Box::new(val)))
             }
             ASTNode::Print(expr) => {
-                let val = self.interpret(expr);
-                println!("{}", self.value_to_string(&val));
-                Value::Null
+                let val = self.interpret(expr)?;
+                println!("{}", self.value_to_string(&val)?);
+                Ok(Value::Null)
             }
             ASTNode::List(elements) => {
-                let mut list = Vec::new();
-                for elem in elements {
-                    list.push(self.interpret(elem));
+                let items = self.evaluate_elements(elements)?;
+                Ok(Value::list(items))
+            }
+            ASTNode::Tuple(elements) => Ok(Value::Tuple(self.evaluate_elements(elements)?)),
+            ASTNode::Dict(entries) => {
+                let mut dict = Dict::new();
+                for (key, value) in entries {
+                    let key = self.interpret(key)?;
+                    let value = self.interpret(value)?;
+                    dict.insert(key, value)?;
                 }
-                Value::List(list)
+                Ok(Value::dict(dict))
             }
-            ASTNode::Tuple(elements) => {
-                let mut tuple = Vec::new();
+            ASTNode::Set(elements) => {
+                let mut set = Set::new();
                 for elem in elements {
-                    tuple.push(self.interpret(elem));
+                    set.insert(self.interpret(elem)?, ())?;
+                }
+                Ok(Value::Set(set))
+            }
+            ASTNode::ListComprehension { element, clauses } => {
+                let generator = self.create_generator(element, clauses)?;
+                let items = self.iterate(&generator)?;
+                Ok(Value::list(items))
+            }
+            ASTNode::SetComprehension { element, clauses } => {
+                let generator = self.create_generator(element, clauses)?;
+                let mut set = Set::new();
+                for item in self.iterate(&generator)? {
+                    set.insert(item, ())?;
                 }
-                Value::Tuple(tuple)
+                Ok(Value::Set(set))
+            }
+            ASTNode::DictComprehension { key, value, clauses } => {
+                let pair = ASTNode::Tuple(vec![*key.clone(), *value.clone()]);
+                let generator = self.create_generator(&pair, clauses)?;
+                let mut dict = Dict::new();
+                for item in self.iterate(&generator)? {
+                    if let Value::Tuple(mut pair) = item {
+                        let value = pair.pop().unwrap();
+                        let key = pair.pop().unwrap();
+                        dict.insert(key, value)?;
+                    }
+                }
+                Ok(Value::dict(dict))
+            }
+            ASTNode::GeneratorExpression { element, clauses } => {
+                self.create_generator(element, clauses)
             }
+            ASTNode::Starred(_) => Err(Exception::type_error("Stern-Ausdruck ist hier nicht erlaubt")),
         }
     }
 
-    fn evaluate_binary_op(&self, left: &Value, operator: &str, right: &Value) -> Value {
-        match operator {
-            "+" => {
-                match (left, right) {
-                (Value::Number(l), Value::Number(r)) => Value::Number(l + r),
-                (Value::String(l), Value::String(r)) => Value::String(l.clone() + r),
-                (Value::List(l), Value::List(r)) => Value::List([l.clone(), r.clone()].concat()),
-                // Optionale Unterstützung für Tupel-Konkatenation
-                (Value::Tuple(l), Value::Tuple(r)) => Value::Tuple([l.clone(), r.clone()].concat()),
-                _ => panic!("Ungültiger Operandentyp für '+': {:?} und {:?}", left, right),
-            }
+    // Sucht einen Namen in den Bereichen, dann bei den eingebauten Funktionen und Klassen
+    fn lookup_variable(&self, name: &str) -> Result<Value> {
+        let value = self
+            .environment
+            .borrow()
+            .get(name)
+            .or_else(|| builtins::lookup(name))
+            .or_else(|| self.exception_classes.get(name).map(|class| Value::Class(Rc::clone(class))));
+        value.ok_or_else(|| Exception::new("NameError", format!("Name '{}' ist nicht definiert", name)))
+    }
 
+    // Lädt ein Modul höchstens einmal; jede Datei läuft in ihrem eigenen globalen Bereich.
+    // Eingebaute Module haben Vorrang und liegen im Zwischenspeicher unter ihrem bloßen Namen.
+    fn import_module(&mut self, name: &str) -> Result<Value> {
+        if let Some(entries) = modules::native_globals(name) {
+            let key = PathBuf::from(name);
+            if let Some(module) = self.modules.get(&key) {
+                return Ok(module.clone());
             }
-            "-" => {
-                if let (Value::Number(l), Value::Number(r)) = (left, right) {
-                    Value::Number(l - r)
-                } else {
-                    panic!("Ungültiger Operandentyp für '-': {:?} und {:?}", left, right);
-                }
+            let mut globals = Environment::default();
+            globals.set("__name__".to_string(), Value::String(name.to_string()));
+            for (entry, value) in entries {
+                globals.set(entry, value);
             }
-            "*" => {
-                if let (Value::Number(l), Value::Number(r)) = (left, right) {
-                    Value::Number(l * r)
-                } else {
-                    panic!("Ungültiger Operandentyp für '*': {:?} und {:?}", left, right);
+            for exception in modules::native_exceptions(name) {
+                let class = Rc::clone(&self.module_exceptions[*exception]);
+                globals.set(exception.to_string(), Value::Class(class));
+            }
+            let module = Value::Module(Rc::new(Module {
+                name: name.to_string(),
+                globals: Rc::new(RefCell::new(globals)),
+            }));
+            self.modules.insert(key, module.clone());
+            return Ok(module);
+        }
+        let importer = self
+            .frames
+            .last()
+            .and_then(|frame| self.sources.get(frame.span.file))
+            .map_or_else(PathBuf::new, |source| PathBuf::from(&source.path));
+        let path = modules::resolve(name, &importer).ok_or_else(|| {
+            Exception::new("ModuleNotFoundError", format!("Kein Modul namens '{}'", name))
+        })?;
+        let path = fs::canonicalize(&path).unwrap_or(path);
+        if let Some(module) = self.modules.get(&path) {
+            return Ok(module.clone());
+        }
+        if let Some(start) = self.importing.iter().position(|loading| *loading == path) {
+            let chain: Vec<String> = self.importing[start..]
+                .iter()
+                .chain(std::iter::once(&path))
+                .map(|path| path.file_stem().unwrap_or_default().to_string_lossy().into_owned())
+                .collect();
+            return Err(Exception::new(
+                "ImportError",
+                format!("Zirkulärer Import: {}", chain.join(" -> ")),
+            ));
+        }
+        let code = fs::read_to_string(&path).map_err(|error| {
+            Exception::new("ImportError", format!("Modul '{}' kann nicht gelesen werden: {}", name, error))
+        })?;
+        let file = self.add_source(&path.display().to_string(), &code);
+        let ast = Parser::from_source(&code, file).parse();
+
+        let globals = Rc::new(RefCell::new(Environment::default()));
+        globals.borrow_mut().set("__name__".to_string(), Value::String(name.to_string()));
+        let previous = std::mem::replace(&mut self.environment, Rc::clone(&globals));
+        self.importing.push(path.clone());
+        self.frames.push(Frame {
+            function: Value::Null,
+            span: Span::default(),
+        });
+        let result = self.interpret(&ast);
+        self.pop_frame(&result);
+        self.importing.pop();
+        self.environment = previous;
+        result?;
+
+        let module = Value::Module(Rc::new(Module {
+            name: name.to_string(),
+            globals,
+        }));
+        self.modules.insert(path, module.clone());
+        Ok(module)
+    }
+
+    fn context_method(&self, manager: &Value, name: &str) -> Result<Value> {
+        self.get_attribute(manager.clone(), name).map_err(|_| {
+            Exception::type_error(format!(
+                "'{}'-Objekt unterstützt das Kontextmanager-Protokoll nicht (fehlt: {})",
+                match manager {
+                    Value::Instance(instance) => instance.class.name.as_str(),
+                    other => type_name(other),
+                },
+                name
+            ))
+        })
+    }
+
+    // Führt den ersten passenden `except`-Zweig aus; passt keiner, geht die Ausnahme weiter
+    fn handle_exception(&mut self, exception: Value, handlers: &[ExceptHandler]) -> Result<Value> {
+        for handler in handlers {
+            if let Some(exception_type) = &handler.exception_type {
+                let exception_type = self.interpret(exception_type)?;
+                if !self.exception_matches(&exception, &exception_type)? {
+                    continue;
                 }
             }
-            "/" => {
-                if let (Value::Number(l), Value::Number(r)) = (left, right) {
-                    if *r == 0.0 {
-                        panic!("Division durch Null");
+            self.traceback = None;
+            if let Some(name) = &handler.name {
+                self.environment.borrow_mut().set(name.clone(), exception.clone());
+            }
+            self.handling.push(exception);
+            let result = self.interpret(&handler.body);
+            self.handling.pop();
+            return result;
+        }
+        Err(Exception::Raised(exception))
+    }
+
+    fn exception_matches(&self, exception: &Value, exception_type: &Value) -> Result<bool> {
+        let Value::Instance(instance) = exception else {
+            return Ok(false);
+        };
+        let candidates = match exception_type {
+            Value::Tuple(items) => items.clone(),
+            other => vec![other.clone()],
+        };
+        for candidate in candidates {
+            match candidate {
+                Value::Class(class) if class.is_subclass(&self.exception_classes["BaseException"]) => {
+                    if instance.class.is_subclass(&class) {
+                        return Ok(true);
                     }
-                    Value::Number(l / r)
-                } else {
-                    panic!("Ungültiger Operandentyp für '/': {:?} und {:?}", left, right);
+                }
+                other => {
+                    return Err(Exception::type_error(format!(
+                        "'except' erwartet Ausnahmeklassen, nicht '{}'",
+                        type_name(&other)
+                    )))
                 }
             }
-            "%" => {
-                if let (Value::Number(l), Value::Number(r)) = (left, right) {
-                    Value::Number(l % r)
-                } else {
-                    panic!("Ungültiger Operandentyp für '%': {:?} und {:?}", left, right);
+        }
+        Ok(false)
+    }
+
+    // `raise Klasse` erzeugt ein Objekt ohne Argumente; nur Ausnahmen dürfen ausgelöst werden
+    fn make_exception(&mut self, value: Value) -> Result<Value> {
+        let root = Rc::clone(&self.exception_classes["BaseException"]);
+        match &value {
+            Value::Class(class) if class.is_subclass(&root) => {
+                self.instantiate(class, Vec::new(), Vec::new())
+            }
+            Value::Instance(instance) if instance.class.is_subclass(&root) => Ok(value),
+            other => Err(Exception::type_error(format!(
+                "Ausnahmen müssen von BaseException erben, nicht '{}'",
+                type_name(other)
+            ))),
+        }
+    }
+
+    // Macht aus einem internen Fehler ein Objekt seiner eingebauten Ausnahmeklasse
+    fn exception_object(&mut self, exception: Exception) -> Value {
+        match exception {
+            Exception::Raised(value) => value,
+            Exception::Builtin { kind, message } => {
+                let class = match self.exception_classes.get(kind) {
+                    Some(class) => Rc::clone(class),
+                    None => Rc::clone(&self.module_exceptions[kind]),
+                };
+                let mut fields = HashMap::new();
+                fields.insert("args".to_string(), Value::Tuple(vec![Value::String(message)]));
+                Value::Instance(Rc::new(Instance {
+                    class,
+                    fields: RefCell::new(fields),
+                }))
+            }
+        }
+    }
+
+    // Meldung für eine nicht abgefangene Ausnahme: der Traceback, gefolgt von z. B.
+    // "KeyError: Schlüssel nicht gefunden: x"
+    pub fn describe_exception(&mut self, exception: Exception) -> String {
+        let traceback = match self.traceback.take() {
+            Some(entries) => exceptions::format_traceback(&self.sources, &entries),
+            None => String::new(),
+        };
+        let value = self.exception_object(exception);
+        let name = match &value {
+            Value::Instance(instance) => instance.class.name.clone(),
+            other => type_name(other).to_string(),
+        };
+        let message = match self.value_to_string(&value) {
+            Ok(message) if message.is_empty() => name,
+            Ok(message) => format!("{}: {}", name, message),
+            Err(_) => format!("{}: <Fehler in __str__>", name),
+        };
+        traceback + &message
+    }
+
+    fn set_position(&mut self, span: Span) {
+        if let Some(frame) = self.frames.last_mut() {
+            frame.span = span;
+        }
+    }
+
+    // Ein neuer Rahmen beginnt an der Aufrufstelle, bis seine erste Anweisung läuft
+    fn push_frame(&mut self, function: &Value) {
+        let span = self.frames.last().map(|frame| frame.span).unwrap_or_default();
+        self.frames.push(Frame {
+            function: function.clone(),
+            span,
+        });
+    }
+
+    // Ein Fehler hält den Stapel fest, solange der Rahmen des Aufrufs noch besteht
+    fn pop_frame(&mut self, result: &Result<Value>) {
+        if result.is_err() && self.traceback.is_none() {
+            self.traceback = Some(self.capture_traceback());
+        }
+        self.frames.pop();
+    }
+
+    fn capture_traceback(&self) -> Vec<TraceEntry> {
+        self.frames
+            .iter()
+            .map(|frame| TraceEntry {
+                function: match &frame.function {
+                    Value::Function { name, .. } => name.clone(),
+                    Value::Lambda { .. } => "<lambda>".to_string(),
+                    _ => "<module>".to_string(),
+                },
+                span: frame.span,
+            })
+            .collect()
+    }
+
+    // Comprehensions laufen in einem eigenen Bereich, damit Schleifenvariablen nicht nach
+    // außen dringen. Das erste Iterable wird sofort im umgebenden Bereich ausgewertet.
+    fn create_generator(&mut self, element: &ASTNode, clauses: &[ComprehensionClause]) -> Result<Value> {
+        let first = self.interpret(&clauses[0].iterable)?;
+        let iterator = self.iterate(&first)?.into_iter();
+        let scope = Environment {
+            values: HashMap::new(),
+            parent: Some(Rc::clone(&self.environment)),
+        };
+        Ok(Value::Generator(Rc::new(RefCell::new(Generator {
+            element: element.clone(),
+            clauses: clauses.to_vec(),
+            scope: Rc::new(RefCell::new(scope)),
+            iterators: vec![iterator],
+        }))))
+    }
+
+    // Liefert das nächste Element eines Generators oder `None`, wenn er erschöpft ist
+    pub(crate) fn next_from_generator(&mut self, generator: &Rc<RefCell<Generator>>) -> Result<Option<Value>> {
+        // Ein Generator, der sich im eigenen Rumpf selbst weiterführt, läuft bereits
+        let Ok(mut state) = generator.try_borrow_mut() else {
+            return Err(Exception::value_error("Generator läuft bereits"));
+        };
+        let generator = &mut *state;
+        let caller_env = std::mem::replace(&mut self.environment, Rc::clone(&generator.scope));
+        let result = self.resume_generator(generator);
+        self.environment = caller_env;
+        result
+    }
+
+    fn resume_generator(&mut self, generator: &mut Generator) -> Result<Option<Value>> {
+        while !generator.iterators.is_empty() {
+            let depth = generator.iterators.len() - 1;
+            let Some(item) = generator.iterators[depth].next() else {
+                generator.iterators.pop();
+                continue;
+            };
+            let clause = &generator.clauses[depth];
+            self.assign(&clause.target, item)?;
+            let mut accepted = true;
+            for condition in &clause.conditions {
+                let cond_value = self.interpret(condition)?;
+                if !self.is_truthy(&cond_value)? {
+                    accepted = false;
+                    break;
                 }
             }
-            "==" => Value::Boolean(left == right),
-            "!=" => Value::Boolean(left != right),
-            "<" => {
-                if let (Value::Number(l), Value::Number(r)) = (left, right) {
-                    Value::Boolean(l < r)
-                } else {
-                    panic!("Ungültiger Operandentyp für '<': {:?} und {:?}", left, right);
+            if !accepted {
+                continue;
+            }
+            if depth + 1 < generator.clauses.len() {
+                let iterable = self.interpret(&generator.clauses[depth + 1].iterable)?;
+                let iterator = self.iterate(&iterable)?.into_iter();
+                generator.iterators.push(iterator);
+            } else {
+                return Ok(Some(self.interpret(&generator.element)?));
+            }
+        }
+        Ok(None)
+    }
+
+    // Wertet Listen- und Tupelelemente aus; `*xs` wird dabei ausgepackt
+    fn evaluate_elements(&mut self, elements: &[ASTNode]) -> Result<Vec<Value>> {
+        let mut values = Vec::new();
+        for elem in elements {
+            if let ASTNode::Starred(inner) = elem {
+                let iterable = self.interpret(inner)?;
+                values.extend(self.iterate(&iterable)?);
+            } else {
+                values.push(self.interpret(elem)?);
+            }
+        }
+        Ok(values)
+    }
+
+    fn assign(&mut self, target: &ASTNode, value: Value) -> Result<()> {
+        match target {
+            ASTNode::Variable(name) => self.environment.borrow_mut().set(name.clone(), value),
+            ASTNode::Index { .. } | ASTNode::Attribute { .. } => {
+                let place = self.resolve_place(target)?;
+                self.store_place(&place, value)?;
+            }
+            ASTNode::Tuple(targets) | ASTNode::List(targets) => {
+                let items = self.iterate(&value)?;
+                let star = targets.iter().position(|t| matches!(t, ASTNode::Starred(_)));
+                match star {
+                    None => {
+                        if items.len() != targets.len() {
+                            return Err(Exception::value_error(format!(
+                                "Falsche Anzahl von Werten zum Entpacken (erwartet {}, erhalten {})",
+                                targets.len(),
+                                items.len()
+                            )));
+                        }
+                        for (target, item) in targets.iter().zip(items) {
+                            self.assign(target, item)?;
+                        }
+                    }
+                    Some(star) => {
+                        let after = targets.len() - star - 1;
+                        if items.len() < targets.len() - 1 {
+                            return Err(Exception::value_error(format!(
+                                "Nicht genug Werte zum Entpacken (erwartet mindestens {}, erhalten {})",
+                                targets.len() - 1,
+                                items.len()
+                            )));
+                        }
+                        let rest_end = items.len() - after;
+                        for (target, item) in targets[..star].iter().zip(&items[..star]) {
+                            self.assign(target, item.clone())?;
+                        }
+                        if let ASTNode::Starred(inner) = &targets[star] {
+                            self.assign(inner, Value::list(items[star..rest_end].to_vec()))?;
+                        }
+                        for (target, item) in targets[star + 1..].iter().zip(&items[rest_end..]) {
+                            self.assign(target, item.clone())?;
+                        }
+                    }
                 }
             }
-            ">" => {
-                if let (Value::Number(l), Value::Number(r)) = (left, right) {
-                    Value::Boolean(l > r)
-                } else {
-                    panic!("Ungültiger Operandentyp für '>': {:?} und {:?}", left, right);
+            _ => return Err(Exception::type_error(format!("Ungültiges Zuweisungsziel: {:?}", target))),
+        }
+        Ok(())
+    }
+
+    pub(crate) fn call_function(
+        &mut self,
+        func: &Value,
+        args: Vec<Value>,
+        kwargs: Vec<(String, Value)>,
+    ) -> Result<Value> {
+        match func {
+            Value::Function { name, params, body, closure } => {
+                let locals = self.bind_arguments(name, params, args, kwargs)?;
+                self.push_frame(func);
+                let result = self.call_in_scope(closure, locals, body);
+                self.pop_frame(&result);
+                match result? {
+                    Value::ReturnValue(val) => Ok(*val),
+                    _ => Ok(Value::Null),
                 }
             }
-            "<=" => {
-                if let (Value::Number(l), Value::Number(r)) = (left, right) {
-                    Value::Boolean(l <= r)
-                } else {
-                    panic!("Ungültiger Operandentyp für '<=': {:?} und {:?}", left, right);
+            Value::Lambda { params, body, closure } => {
+                let locals = self.bind_arguments("<lambda>", params, args, kwargs)?;
+                self.push_frame(func);
+                let result = self.call_in_scope(closure, locals, body);
+                self.pop_frame(&result);
+                result
+            }
+            Value::Builtin(name) => builtins::call(self, name, args, kwargs),
+            Value::Class(class) => self.instantiate(class, args, kwargs),
+            Value::Instance(instance) => match instance.class.lookup("__call__") {
+                Some(method) => {
+                    let mut args = args;
+                    args.insert(0, func.clone());
+                    self.call_function(&method, args, kwargs)
                 }
+                None => Err(Exception::type_error(format!(
+                    "'{}'-Objekt ist nicht aufrufbar",
+                    instance.class.name
+                ))),
+            },
+            Value::Method { receiver, function } => {
+                let mut args = args;
+                args.insert(0, (**receiver).clone());
+                self.call_function(function, args, kwargs)
             }
-            ">=" => {
-                if let (Value::Number(l), Value::Number(r)) = (left, right) {
-                    Value::Boolean(l >= r)
-                } else {
-                    panic!("Ungültiger Operandentyp für '>=': {:?} und {:?}", left, right);
+            Value::BoundMethod { receiver, name } => {
+                methods::call_method(self, receiver, name, args, kwargs)
+            }
+            _ => Err(Exception::type_error(format!(
+                "Wert vom Typ '{}' ist nicht aufrufbar",
+                type_name(func)
+            ))),
+        }
+    }
+
+    // Erzeugt ein Objekt und ruft `__init__` mit den Argumenten auf
+    fn instantiate(
+        &mut self,
+        class: &Rc<Class>,
+        args: Vec<Value>,
+        kwargs: Vec<(String, Value)>,
+    ) -> Result<Value> {
+        let instance = Value::Instance(Rc::new(Instance {
+            class: Rc::clone(class),
+            fields: RefCell::new(HashMap::new()),
+        }));
+        match class.lookup("__init__") {
+            Some(init) => {
+                let mut args = args;
+                args.insert(0, instance.clone());
+                if self.call_function(&init, args, kwargs)? != Value::Null {
+                    return Err(Exception::type_error(format!(
+                        "__init__() von '{}' muss None zurückgeben",
+                        class.name
+                    )));
                 }
             }
-            "and" => {
-                Value::Boolean(self.is_truthy(left) && self.is_truthy(right))
+            None if !args.is_empty() || !kwargs.is_empty() => {
+                return Err(Exception::type_error(format!(
+                    "'{}' erwartet keine Argumente",
+                    class.name
+                )))
             }
-            "or" => {
-                Value::Boolean(self.is_truthy(left) || self.is_truthy(right))
+            None => {}
+        }
+        Ok(instance)
+    }
+
+    // `super()` ohne Argumente nimmt die Klasse der laufenden Methode und deren erstes Argument
+    pub(crate) fn make_super(&self, args: Vec<Value>) -> Result<Value> {
+        let outside_method = || Exception::new("RuntimeError", "super(): außerhalb einer Methode aufgerufen");
+        let (class, receiver) = match args.as_slice() {
+            [] => {
+                let frame = self.frames.last().ok_or_else(outside_method)?;
+                let Value::Function { params, closure, .. } = &frame.function else {
+                    return Err(outside_method());
+                };
+                let Some(Value::Class(class)) = closure.borrow().get("__class__") else {
+                    return Err(Exception::new("RuntimeError", "super(): außerhalb einer Klasse aufgerufen"));
+                };
+                let receiver = params
+                    .first()
+                    .and_then(|param| self.environment.borrow().get(param))
+                    .ok_or_else(|| Exception::new("RuntimeError", "super(): Methode hat kein erstes Argument"))?;
+                (class, receiver)
+            }
+            [Value::Class(class), receiver] => (Rc::clone(class), receiver.clone()),
+            _ => {
+                return Err(Exception::type_error(
+                    "super() erwartet keine Argumente oder eine Klasse und ein Objekt",
+                ))
             }
-            _ => panic!("Unbekannter Operator: {}", operator),
+        };
+        let valid = match &receiver {
+            Value::Instance(instance) => instance.class.is_subclass(&class),
+            Value::Class(other) => other.is_subclass(&class),
+            _ => false,
+        };
+        if !valid {
+            return Err(Exception::type_error(format!(
+                "super(): Objekt ist keine Instanz von '{}'",
+                class.name
+            )));
         }
+        Ok(Value::Super {
+            class,
+            receiver: Box::new(receiver),
+        })
     }
 
-    fn evaluate_unary_op(&self, operator: &str, operand: &Value) -> Value {
-        match operator {
-            "-" => {
-                if let Value::Number(v) = operand {
-                    Value::Number(-v)
-                } else {
-                    panic!("Ungültiger Operandentyp für '-': {:?}", operand);
+    fn bind_arguments(
+        &self,
+        name: &str,
+        params: &[String],
+        args: Vec<Value>,
+        kwargs: Vec<(String, Value)>,
+    ) -> Result<Environment> {
+        if args.len() > params.len() {
+            return Err(Exception::type_error(format!(
+                "Falsche Anzahl von Argumenten für Funktion '{}'",
+                name
+            )));
+        }
+        let mut locals = Environment::default();
+        let mut bound = vec![false; params.len()];
+        for (i, val) in args.into_iter().enumerate() {
+            locals.set(params[i].clone(), val);
+            bound[i] = true;
+        }
+        for (key, val) in kwargs {
+            match params.iter().position(|p| *p == key) {
+                Some(i) if !bound[i] => {
+                    locals.set(key, val);
+                    bound[i] = true;
                 }
-            }
-            "+" => {
-                if let Value::Number(v) = operand {
-                    Value::Number(*v)
-                } else {
-                    panic!("Ungültiger Operandentyp für '+': {:?}", operand);
+                Some(_) => {
+                    return Err(Exception::type_error(format!(
+                        "Mehrfacher Wert für Parameter '{}' in Funktion '{}'",
+                        key, name
+                    )))
+                }
+                None => {
+                    return Err(Exception::type_error(format!(
+                        "Unbekanntes Schlüsselwortargument '{}' für Funktion '{}'",
+                        key, name
+                    )))
                 }
             }
-            "not" => {
-                Value::Boolean(!self.is_truthy(operand))
-            }
-            _ => panic!("Unbekannter Operator: {}", operator),
         }
+        if bound.contains(&false) {
+            return Err(Exception::type_error(format!(
+                "Falsche Anzahl von Argumenten für Funktion '{}'",
+                name
+            )));
+        }
+        Ok(locals)
     }
 
-    fn is_truthy(&self, value: &Value) -> bool {
+    // Führt `body` in einem neuen Bereich unterhalb von `closure` aus
+    fn call_in_scope(
+        &mut self,
+        closure: &Rc<RefCell<Environment>>,
+        mut locals: Environment,
+        body: &ASTNode,
+    ) -> Result<Value> {
+        locals.parent = Some(Rc::clone(closure));
+        let caller_env = std::mem::replace(&mut self.environment, Rc::new(RefCell::new(locals)));
+        let result = self.interpret(body);
+        self.environment = caller_env;
+        result
+    }
+
+    // Liefert die Elemente eines iterierbaren Werts; bei Dicts sind das die Schlüssel
+    pub(crate) fn iterate(&mut self, value: &Value) -> Result<Vec<Value>> {
         match value {
-            Value::Boolean(b) => *b,
-            Value::Number(n) => *n != 0.0,
-            Value::String(s) => !s.is_empty(),
-            Value::List(l) => !l.is_empty(),
-            Value::Tuple(t) => !t.is_empty(),
-            Value::Null => false,
-            _ => true,
+            Value::List(items) => Ok(items.borrow().clone()),
+            Value::Tuple(items) => Ok(items.clone()),
+            Value::Dict(dict) => Ok(dict.borrow().keys().cloned().collect()),
+            Value::Set(set) => Ok(set.keys().cloned().collect()),
+            Value::Generator(generator) => {
+                let mut items = Vec::new();
+                while let Some(item) = self.next_from_generator(generator)? {
+                    items.push(item);
+                }
+                Ok(items)
+            }
+            Value::String(s) => Ok(s.chars().map(|c| Value::String(c.to_string())).collect()),
+            Value::File(file) => file.lines(),
+            // `__iter__` liefert ein iterierbares Objekt, dessen Elemente übernommen werden
+            Value::Instance(instance) => match self.call_special(value, "__iter__", Vec::new())? {
+                Some(Value::Instance(_)) => Err(Exception::type_error(format!(
+                    "__iter__() von '{}' muss eine Liste, ein Tupel oder einen Generator zurückgeben",
+                    instance.class.name
+                ))),
+                Some(iterable) => self.iterate(&iterable),
+                None => Err(Exception::type_error(format!(
+                    "'{}'-Objekt ist nicht iterierbar",
+                    instance.class.name
+                ))),
+            },
+            _ => Err(Exception::type_error(format!(
+                "Wert vom Typ '{}' ist nicht iterierbar",
+                type_name(value)
+            ))),
         }
     }
 
-    fn value_to_string(&self, value: &Value) -> String {
+    // Länge für `len()`; Objekte liefern sie über `__len__`
+    pub(crate) fn length(&mut self, value: &Value) -> Result<usize> {
         match value {
-            Value::Number(n) => n.to_string(),
-            Value::String(s) => s.clone(),
-            Value::Boolean(b) => b.to_string(),
-            Value::List(l) => {
-                let elements: Vec<String> = l.iter().map(|v| self.value_to_string(v)).collect();
-                format!("[{}]", elements.join(", "))
+            Value::String(s) => Ok(s.chars().count()),
+            Value::List(items) => Ok(items.borrow().len()),
+            Value::Tuple(items) => Ok(items.len()),
+            Value::Dict(dict) => Ok(dict.borrow().len()),
+            Value::Set(set) => Ok(set.len()),
+            Value::Instance(instance) => match self.call_special(value, "__len__", Vec::new())? {
+                Some(Value::Number(n)) if n.fract() == 0.0 && n >= 0.0 => Ok(n as usize),
+                Some(other) => Err(Exception::value_error(format!(
+                    "__len__() muss eine nicht-negative ganze Zahl zurückgeben, nicht {}",
+                    self.value_to_string(&other)?
+                ))),
+                None => Err(Exception::type_error(format!(
+                    "'{}'-Objekt hat keine Länge",
+                    instance.class.name
+                ))),
+            },
+            _ => Err(Exception::type_error(format!(
+                "Wert vom Typ '{}' hat keine Länge",
+                type_name(value)
+            ))),
+        }
+    }
+
+    // Ordnung zwischen zwei Werten für `operator`. Zeichenketten, Listen und Tupel werden
+    // lexikographisch verglichen, Wahrheitswerte wie 0 und 1. `None` bedeutet, dass die
+    // Werte ungeordnet sind (NaN); unvergleichbare Typen sind ein Fehler.
+    pub(crate) fn compare_values(
+        &mut self,
+        left: &Value,
+        right: &Value,
+        operator: &str,
+    ) -> Result<Option<Ordering>> {
+        let unsupported = || {
+            Exception::type_error(format!(
+                "'{}' wird zwischen '{}' und '{}' nicht unterstützt",
+                operator,
+                type_name(left),
+                type_name(right)
+            ))
+        };
+        match (left, right) {
+            // Objekte werden über ihr `__lt__` (bzw. das gespiegelte `__gt__`) geordnet
+            (Value::Instance(_), _) | (_, Value::Instance(_)) => {
+                let less = self
+                    .overloaded_binary_op(left, "<", right)?
+                    .ok_or_else(unsupported)?;
+                if self.is_truthy(&less)? {
+                    return Ok(Some(Ordering::Less));
+                }
+                match self.overloaded_binary_op(right, "<", left)? {
+                    Some(greater) if self.is_truthy(&greater)? => Ok(Some(Ordering::Greater)),
+                    _ => Ok(Some(Ordering::Equal)),
+                }
             }
-            Value::Tuple(t) => {
-                let elements: Vec<String> = t.iter().map(|v| self.value_to_string(v)).collect();
-                format!("({})", elements.join(", "))
+            (Value::Number(_) | Value::Boolean(_), Value::Number(_) | Value::Boolean(_)) => {
+                Ok(as_number(left).partial_cmp(&as_number(right)))
             }
-            Value::Null => "null".to_string(),
-            Value::Function { .. } => "<function>".to_string(),
-            Value::ReturnValue(val) => self.value_to_string(val),
+            (Value::String(l), Value::String(r)) => Ok(Some(l.cmp(r))),
+            (Value::List(l), Value::List(r)) => {
+                let (l, r) = (l.borrow().clone(), r.borrow().clone());
+                self.compare_sequences(&l, &r, operator)
+            }
+            (Value::Tuple(l), Value::Tuple(r)) => self.compare_sequences(l, r, operator),
+            _ => Err(unsupported()),
+        }
+    }
+
+    fn compare_sequences(
+        &mut self,
+        left: &[Value],
+        right: &[Value],
+        operator: &str,
+    ) -> Result<Option<Ordering>> {
+        for (a, b) in left.iter().zip(right.iter()) {
+            if !self.values_equal(a, b)? {
+                return self.compare_values(a, b, operator);
+            }
+        }
+        Ok(Some(left.len().cmp(&right.len())))
+    }
+
+    fn evaluate_index(&mut self, object: &Value, index: &Value) -> Result<Value> {
+        if let Value::Instance(instance) = object {
+            return self
+                .call_special(object, "__getitem__", vec![index.clone()])?
+                .ok_or_else(|| {
+                    Exception::type_error(format!(
+                        "'{}'-Objekt ist nicht indizierbar",
+                        instance.class.name
+                    ))
+                });
         }
+        if let Value::Dict(dict) = object {
+            let found = dict.borrow().get(index)?.cloned();
+            return match found {
+                Some(value) => Ok(value),
+                None => Err(Exception::key_error(format!(
+                    "Schlüssel nicht gefunden: {}",
+                    self.value_to_string(index)?
+                ))),
+            };
+        }
+        match object {
+            Value::List(items) => {
+                let items = items.borrow();
+                let pos = resolve_index(expect_integer(index)?, items.len())?;
+                Ok(items[pos].clone())
+            }
+            Value::Tuple(items) => {
+                let pos = resolve_index(expect_integer(index)?, items.len())?;
+                Ok(items[pos].clone())
+            }
+            Value::String(s) => {
+                let chars: Vec<char> = s.chars().collect();
+                let pos = resolve_index(expect_integer(index)?, chars.len())?;
+                Ok(Value::String(chars[pos].to_string()))
+            }
+            _ => Err(Exception::type_error(format!(
+                "Wert vom Typ '{}' ist nicht indizierbar",
+                type_name(object)
+            ))),
+        }
+    }
+
+    // Attributzugriff: Objekte suchen erst in ihren eigenen Attributen, dann in der Klasse;
+    // eingebaute Typen bieten nur ihre Methoden als gebundene Werte an
+    pub(crate) fn get_attribute(&self, object: Value, name: &str) -> Result<Value> {
+        match &object {
+            Value::Instance(instance) => {
+                if let Some(value) = instance.fields.borrow().get(name) {
+                    return Ok(value.clone());
+                }
+                return match instance.class.lookup(name) {
+                    Some(function) if is_method(&function) => Ok(Value::Method {
+                        receiver: Box::new(object.clone()),
+                        function: Box::new(function),
+                    }),
+                    Some(value) => Ok(value),
+                    None => Err(Exception::attribute_error(format!(
+                        "'{}'-Objekt hat kein Attribut '{}'",
+                        instance.class.name, name
+                    ))),
+                };
+            }
+            Value::Class(class) => {
+                return class.lookup(name).ok_or_else(|| {
+                    Exception::attribute_error(format!(
+                        "Klasse '{}' hat kein Attribut '{}'",
+                        class.name, name
+                    ))
+                });
+            }
+            Value::Module(module) => {
+                return module.globals.borrow().values.get(name).cloned().ok_or_else(|| {
+                    Exception::attribute_error(format!(
+                        "Modul '{}' hat kein Attribut '{}'",
+                        module.name, name
+                    ))
+                });
+            }
+            Value::Super { class, receiver } => {
+                let found = class.base.as_ref().and_then(|base| base.lookup(name));
+                return match found {
+                    Some(function) if is_method(&function) => Ok(Value::Method {
+                        receiver: receiver.clone(),
+                        function: Box::new(function),
+                    }),
+                    Some(value) => Ok(value),
+                    None => Err(Exception::attribute_error(format!(
+                        "'super' hat kein Attribut '{}'",
+                        name
+                    ))),
+                };
+            }
+            _ => {}
+        }
+        if methods::has_method(&object, name) {
+            return Ok(Value::BoundMethod {
+                receiver: Box::new(object),
+                name: name.to_string(),
+            });
+        }
+        Err(Exception::attribute_error(format!(
+            "'{}' hat kein Attribut '{}'",
+            type_name(&object),
+            name
+        )))
+    }
+
+    pub(crate) fn set_attribute(&self, object: &Value, name: &str, value: Value) -> Result<()> {
+        match object {
+            Value::Instance(instance) => {
+                instance.fields.borrow_mut().insert(name.to_string(), value);
+                Ok(())
+            }
+            Value::Class(class) => {
+                class.attributes.borrow_mut().insert(name.to_string(), value);
+                Ok(())
+            }
+            Value::Module(module) => {
+                module.globals.borrow_mut().set(name.to_string(), value);
+                Ok(())
+            }
+            _ => Err(Exception::attribute_error(format!(
+                "Attribut '{}' von '{}' kann nicht gesetzt werden",
+                name,
+                type_name(object)
+            ))),
+        }
+    }
+
+    fn resolve_place(&mut self, target: &ASTNode) -> Result<Place> {
+        match target {
+            ASTNode::Variable(name) => Ok(Place::Variable(name.clone())),
+            ASTNode::Index { object, index } => {
+                let container = self.interpret(object)?;
+                let index_val = self.interpret(index)?;
+                Ok(Place::Index(container, index_val))
+            }
+            ASTNode::Attribute { object, name } => {
+                let object_val = self.interpret(object)?;
+                Ok(Place::Attribute(object_val, name.clone()))
+            }
+            _ => Err(Exception::type_error(format!("Ungültiges Zuweisungsziel: {:?}", target))),
+        }
+    }
+
+    fn load_place(&mut self, place: &Place) -> Result<Value> {
+        match place {
+            Place::Variable(name) => self.lookup_variable(name),
+            Place::Index(container, index) => self.evaluate_index(container, index),
+            Place::Attribute(object, name) => self.get_attribute(object.clone(), name),
+        }
+    }
+
+    fn store_place(&mut self, place: &Place, value: Value) -> Result<()> {
+        match place {
+            Place::Variable(name) => {
+                self.environment.borrow_mut().set(name.clone(), value);
+                Ok(())
+            }
+            Place::Index(container, index) => self.set_item(container, index, value),
+            Place::Attribute(object, name) => self.set_attribute(object, name, value),
+        }
+    }
+
+    fn set_item(&mut self, container: &Value, index: &Value, value: Value) -> Result<()> {
+        match container {
+            Value::Instance(instance) => {
+                if self
+                    .call_special(container, "__setitem__", vec![index.clone(), value])?
+                    .is_none()
+                {
+                    return Err(Exception::type_error(format!(
+                        "'{}'-Objekt unterstützt keine Elementzuweisung",
+                        instance.class.name
+                    )));
+                }
+            }
+            Value::List(items) => {
+                let mut items = items.borrow_mut();
+                let pos = resolve_index(expect_integer(index)?, items.len())?;
+                items[pos] = value;
+            }
+            Value::Dict(dict) => {
+                dict.borrow_mut().insert(index.clone(), value)?;
+            }
+            _ => {
+                return Err(Exception::type_error(format!(
+                    "Wert vom Typ '{}' unterstützt keine Elementzuweisung",
+                    type_name(container)
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    fn evaluate_binary_op(&mut self, left: &Value, operator: &str, right: &Value) -> Result<Value> {
+        if let Some(result) = self.overloaded_binary_op(left, operator, right)? {
+            return Ok(result);
+        }
+        match operator {
+            "in" => Ok(Value::Boolean(self.contains(right, left)?)),
+            "not in" => Ok(Value::Boolean(!self.contains(right, left)?)),
+            "is" => Ok(Value::Boolean(self.is_identical(left, right))),
+            "is not" => Ok(Value::Boolean(!self.is_identical(left, right))),
+            "==" => Ok(Value::Boolean(self.values_equal(left, right)?)),
+            "!=" => Ok(Value::Boolean(!self.values_equal(left, right)?)),
+            "<" | ">" | "<=" | ">=" => {
+                let result = match self.compare_values(left, right, operator)? {
+                    Some(ordering) => match operator {
+                        "<" => ordering == Ordering::Less,
+                        ">" => ordering == Ordering::Greater,
+                        "<=" => ordering != Ordering::Greater,
+                        _ => ordering != Ordering::Less,
+                    },
+                    None => false,
+                };
+                Ok(Value::Boolean(result))
+            }
+            "and" => {
+                Ok(Value::Boolean(self.is_truthy(left)? && self.is_truthy(right)?))
+            }
+            "or" => {
+                Ok(Value::Boolean(self.is_truthy(left)? || self.is_truthy(right)?))
+            }
+            _ if operators::is_binary_operator(operator) => {
+                operators::apply_binary(left, operator, right)
+            }
+            _ => Err(Exception::type_error(format!("Unbekannter Operator: {}", operator))),
+        }
+    }
+
+    // Operatoren auf Objekten: erst die Methode des linken Operanden, dann die gespiegelte
+    // des rechten. `!=` fällt ohne `__ne__` auf die Verneinung von `__eq__` zurück.
+    fn overloaded_binary_op(&mut self, left: &Value, operator: &str, right: &Value) -> Result<Option<Value>> {
+        if !matches!(left, Value::Instance(_)) && !matches!(right, Value::Instance(_)) {
+            return Ok(None);
+        }
+        let Some((method, reflected)) = operators::special_methods(operator) else {
+            return Ok(None);
+        };
+        if let Some(result) = self.call_special(left, method, vec![right.clone()])? {
+            return Ok(Some(result));
+        }
+        if let Some(result) = self.call_special(right, reflected, vec![left.clone()])? {
+            return Ok(Some(result));
+        }
+        if operator == "!=" {
+            if let Some(equal) = self.call_special(left, "__eq__", vec![right.clone()])? {
+                return Ok(Some(Value::Boolean(!self.is_truthy(&equal)?)));
+            }
+        }
+        Ok(None)
+    }
+
+    // Ruft eine Spezialmethode wie `__len__` auf, falls die Klasse des Objekts sie definiert
+    fn call_special(&mut self, value: &Value, name: &str, args: Vec<Value>) -> Result<Option<Value>> {
+        let Value::Instance(instance) = value else {
+            return Ok(None);
+        };
+        let Some(method) = instance.class.lookup(name) else {
+            return Ok(None);
+        };
+        let mut call_args = vec![value.clone()];
+        call_args.extend(args);
+        self.call_function(&method, call_args, Vec::new()).map(Some)
+    }
+
+    // Gleichheit wie bei `==`; Objekte mit `__eq__` werden auch in Sequenzen berücksichtigt
+    pub(crate) fn values_equal(&mut self, left: &Value, right: &Value) -> Result<bool> {
+        match (left, right) {
+            (Value::Instance(_), _) | (_, Value::Instance(_)) => {
+                match self.overloaded_binary_op(left, "==", right)? {
+                    Some(result) => self.is_truthy(&result),
+                    None => Ok(left == right),
+                }
+            }
+            // Wie in Python ist ein Container sich selbst gleich, ohne seine Elemente anzusehen.
+            // Ein Paar, das weiter oben schon verglichen wird, gilt als gleich, damit
+            // verschachtelte Zyklen den Vergleich beenden.
+            (Value::List(l), Value::List(r)) => {
+                let pair = (Rc::as_ptr(l) as *const (), Rc::as_ptr(r) as *const ());
+                if Rc::ptr_eq(l, r) || self.comparing.contains(&pair) {
+                    return Ok(true);
+                }
+                let (l, r) = (l.borrow().clone(), r.borrow().clone());
+                self.comparing.push(pair);
+                let result = self.sequences_equal(&l, &r);
+                self.comparing.pop();
+                result
+            }
+            (Value::Dict(l), Value::Dict(r)) => {
+                let pair = (Rc::as_ptr(l) as *const (), Rc::as_ptr(r) as *const ());
+                if Rc::ptr_eq(l, r) || self.comparing.contains(&pair) {
+                    return Ok(true);
+                }
+                let (l, r) = (l.borrow().clone(), r.borrow().clone());
+                self.comparing.push(pair);
+                let result = self.dicts_equal(&l, &r);
+                self.comparing.pop();
+                result
+            }
+            (Value::Tuple(l), Value::Tuple(r)) => self.sequences_equal(l, r),
+            _ => Ok(left == right),
+        }
+    }
+
+    fn dicts_equal(&mut self, left: &Dict, right: &Dict) -> Result<bool> {
+        if left.len() != right.len() {
+            return Ok(false);
+        }
+        for (key, value) in left.iter() {
+            match right.get(key)? {
+                Some(other) if self.values_equal(value, other)? => {}
+                _ => return Ok(false),
+            }
+        }
+        Ok(true)
+    }
+
+    fn sequences_equal(&mut self, left: &[Value], right: &[Value]) -> Result<bool> {
+        if left.len() != right.len() {
+            return Ok(false);
+        }
+        for (a, b) in left.iter().zip(right) {
+            if !self.values_equal(a, b)? {
+                return Ok(false);
+            }
+        }
+        Ok(true)
+    }
+
+    // Sucht `item` per `==` unter den Elementen
+    fn contains_equal(&mut self, items: &[Value], item: &Value) -> Result<bool> {
+        for candidate in items {
+            if self.values_equal(candidate, item)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    fn contains(&mut self, container: &Value, item: &Value) -> Result<bool> {
+        match container {
+            Value::Instance(_) => match self.call_special(container, "__contains__", vec![item.clone()])? {
+                Some(result) => self.is_truthy(&result),
+                None => {
+                    let items = self.iterate(container)?;
+                    self.contains_equal(&items, item)
+                }
+            },
+            Value::List(items) => {
+                let items = items.borrow().clone();
+                self.contains_equal(&items, item)
+            }
+            Value::Tuple(items) => self.contains_equal(items, item),
+            Value::Dict(dict) => dict.borrow().contains_key(item),
+            Value::Set(set) => set.contains_key(item),
+            Value::String(s) => match item {
+                Value::String(sub) => Ok(s.contains(sub.as_str())),
+                _ => Err(Exception::type_error(format!(
+                    "'in <string>' erwartet eine Zeichenkette als linken Operanden, nicht '{}'",
+                    type_name(item)
+                ))),
+            },
+            _ => Err(Exception::type_error(format!(
+                "Wert vom Typ '{}' unterstützt keinen 'in'-Test",
+                type_name(container)
+            ))),
+        }
+    }
+
+    // Listen und Dicts sind nur mit sich selbst identisch, unveränderliche Werte bei Gleichheit
+    pub(crate) fn is_identical(&self, left: &Value, right: &Value) -> bool {
+        match (left, right) {
+            (Value::List(l), Value::List(r)) => Rc::ptr_eq(l, r),
+            (Value::Dict(l), Value::Dict(r)) => Rc::ptr_eq(l, r),
+            _ => left == right,
+        }
+    }
+
+    fn evaluate_unary_op(&mut self, operator: &str, operand: &Value) -> Result<Value> {
+        let special = match operator {
+            "-" => Some("__neg__"),
+            "+" => Some("__pos__"),
+            "~" => Some("__invert__"),
+            _ => None,
+        };
+        if let Some(name) = special {
+            if let Some(result) = self.call_special(operand, name, Vec::new())? {
+                return Ok(result);
+            }
+        }
+        match operator {
+            "-" => {
+                if let Value::Number(v) = operand {
+                    Ok(Value::Number(-v))
+                } else {
+                    Err(Exception::type_error(format!(
+                        "Ungültiger Operandentyp für '-': '{}'",
+                        type_name(operand)
+                    )))
+                }
+            }
+            "+" => {
+                if let Value::Number(v) = operand {
+                    Ok(Value::Number(*v))
+                } else {
+                    Err(Exception::type_error(format!(
+                        "Ungültiger Operandentyp für '+': '{}'",
+                        type_name(operand)
+                    )))
+                }
+            }
+            "~" => {
+                let whole = match operand {
+                    Value::Number(v) => modules::whole_number(*v)?,
+                    _ => None,
+                };
+                match whole {
+                    Some(v) => Ok(Value::Number(!v as f64)),
+                    None => Err(Exception::type_error(format!(
+                        "Ungültiger Operandentyp für '~': '{}' (ganze Zahl erwartet)",
+                        type_name(operand)
+                    ))),
+                }
+            }
+            "not" => {
+                Ok(Value::Boolean(!self.is_truthy(operand)?))
+            }
+            _ => Err(Exception::type_error(format!("Unbekannter Operator: {}", operator))),
+        }
+    }
+
+    pub(crate) fn is_truthy(&mut self, value: &Value) -> Result<bool> {
+        let truthy = match value {
+            // Objekte sind wahr, außer `__bool__` oder `__len__` sagen etwas anderes
+            Value::Instance(instance) => {
+                if let Some(result) = self.call_special(value, "__bool__", Vec::new())? {
+                    match result {
+                        Value::Boolean(b) => b,
+                        other => {
+                            return Err(Exception::type_error(format!(
+                                "__bool__() muss 'bool' zurückgeben, nicht '{}'",
+                                type_name(&other)
+                            )))
+                        }
+                    }
+                } else if instance.class.lookup("__len__").is_some() {
+                    self.length(value)? != 0
+                } else {
+                    true
+                }
+            }
+            Value::Boolean(b) => *b,
+            Value::Number(n) => *n != 0.0,
+            Value::String(s) => !s.is_empty(),
+            Value::List(l) => !l.borrow().is_empty(),
+            Value::Tuple(t) => !t.is_empty(),
+            Value::Dict(d) => !d.borrow().is_empty(),
+            Value::Set(s) => !s.is_empty(),
+            Value::Null => false,
+            _ => true,
+        };
+        Ok(truthy)
+    }
+
+    pub(crate) fn value_to_string(&mut self, value: &Value) -> Result<String> {
+        let text = match value {
+            Value::Number(n) => n.to_string(),
+            Value::String(s) => s.clone(),
+            Value::Boolean(b) => b.to_string(),
+            // Ein Container, der sich selbst enthält, erscheint innen als `[...]` bzw. `{...}`
+            Value::List(l) if self.printing.contains(&(Rc::as_ptr(l) as *const ())) => {
+                "[...]".to_string()
+            }
+            Value::List(l) => {
+                // Kopie, da `__str__` von Elementen die Liste verändern darf
+                let items = l.borrow().clone();
+                self.printing.push(Rc::as_ptr(l) as *const ());
+                let joined = self.join_values(&items);
+                self.printing.pop();
+                format!("[{}]", joined?)
+            }
+            Value::Tuple(t) => format!("({})", self.join_values(t)?),
+            Value::Dict(d) if self.printing.contains(&(Rc::as_ptr(d) as *const ())) => {
+                "{...}".to_string()
+            }
+            Value::Dict(d) => {
+                let dict = d.borrow().clone();
+                self.printing.push(Rc::as_ptr(d) as *const ());
+                let entries = self.join_entries(&dict);
+                self.printing.pop();
+                format!("{{{}}}", entries?)
+            }
+            Value::Set(s) if s.is_empty() => "set()".to_string(),
+            Value::Set(s) => {
+                let items: Vec<Value> = s.keys().cloned().collect();
+                format!("{{{}}}", self.join_values(&items)?)
+            }
+            Value::Generator(_) => "<generator>".to_string(),
+            Value::File(file) => format!("<file '{}' mode '{}'>", file.path, file.mode),
+            Value::Lock(lock) => lock.describe().to_string(),
+            Value::Null => "null".to_string(),
+            Value::Function { name, .. } => format!("<function {}>", name),
+            Value::Lambda { .. } => "<lambda>".to_string(),
+            Value::Builtin(name) => format!("<built-in function {}>", name),
+            Value::Class(class) => format!("<class '{}'>", class.name),
+            Value::Module(module) => format!("<module '{}'>", module.name),
+            // `__str__` hat Vorrang, `__repr__` dient als Ersatz
+            Value::Instance(instance) => {
+                let result = match self.call_special(value, "__str__", Vec::new())? {
+                    Some(result) => Some(result),
+                    None => self.call_special(value, "__repr__", Vec::new())?,
+                };
+                match result {
+                    Some(Value::String(s)) => s,
+                    Some(other) => {
+                        return Err(Exception::type_error(format!(
+                            "__str__()/__repr__() muss 'str' zurückgeben, nicht '{}'",
+                            type_name(&other)
+                        )))
+                    }
+                    None => format!("<{} object>", instance.class.name),
+                }
+            }
+            Value::Super { class, .. } => format!("<super: <class '{}'>>", class.name),
+            Value::Method { receiver, function } => {
+                let class_name = match &**receiver {
+                    Value::Instance(instance) => instance.class.name.clone(),
+                    other => type_name(other).to_string(),
+                };
+                let method_name = match &**function {
+                    Value::Function { name, .. } => name.as_str(),
+                    Value::Builtin(name) => name.rsplit('.').next().unwrap_or(name),
+                    _ => "<lambda>",
+                };
+                format!("<bound method {}.{}>", class_name, method_name)
+            }
+            Value::BoundMethod { receiver, name } => {
+                format!("<built-in method {} of {}>", name, type_name(receiver))
+            }
+            Value::ReturnValue(val) => self.value_to_string(val)?,
+        };
+        Ok(text)
+    }
+
+    fn join_values(&mut self, items: &[Value]) -> Result<String> {
+        let mut parts = Vec::new();
+        for item in items {
+            parts.push(self.value_to_string(item)?);
+        }
+        Ok(parts.join(", "))
+    }
+
+    fn join_entries(&mut self, dict: &Dict) -> Result<String> {
+        let mut entries = Vec::new();
+        for (k, v) in dict.iter() {
+            entries.push(format!("{}: {}", self.value_to_string(k)?, self.value_to_string(v)?));
+        }
+        Ok(entries.join(", "))
+    }
+}
+
+// Funktionen aus Klassenrümpfen und native Methoden eingebauter Klassen binden ihr Objekt
+fn is_method(value: &Value) -> bool {
+    match value {
+        Value::Function { .. } | Value::Lambda { .. } => true,
+        Value::Builtin(name) => exceptions::NATIVE_METHODS.contains(&name.as_str()),
+        _ => false,
+    }
+}
+
+pub(crate) fn type_name(value: &Value) -> &'static str {
+    match value {
+        Value::Number(_) => "number",
+        Value::String(_) => "str",
+        Value::Boolean(_) => "bool",
+        Value::List(_) => "list",
+        Value::Tuple(_) => "tuple",
+        Value::Dict(_) => "dict",
+        Value::Set(_) => "set",
+        Value::Generator(_) => "generator",
+        Value::Function { .. } | Value::Lambda { .. } => "function",
+        Value::Builtin(_) => "builtin_function",
+        Value::Class(_) => "type",
+        Value::Instance(_) => "object",
+        Value::Module(_) => "module",
+        Value::File(_) => "file",
+        Value::Lock(_) => "lock",
+        Value::Method { .. } => "method",
+        Value::Super { .. } => "super",
+        Value::BoundMethod { .. } => "method",
+        Value::ReturnValue(_) => "return",
+        Value::Null => "NoneType",
+    }
+}
+
+fn as_number(value: &Value) -> f64 {
+    match value {
+        Value::Number(n) => *n,
+        Value::Boolean(b) => f64::from(u8::from(*b)),
+        _ => unreachable!(),
+    }
+}
+
+fn expect_integer(index: &Value) -> Result<i64> {
+    match index {
+        Value::Number(n) if n.fract() == 0.0 => Ok(*n as i64),
+        _ => Err(Exception::type_error(format!(
+            "Index muss eine ganze Zahl sein, nicht '{}'",
+            type_name(index)
+        ))),
+    }
+}
+
+// Wandelt einen (ggf. negativen) Index in eine Position um
+fn resolve_index(index: i64, len: usize) -> Result<usize> {
+    let pos = if index < 0 { index + len as i64 } else { index };
+    if pos < 0 || pos >= len as i64 {
+        return Err(Exception::index_error(format!(
+            "Index außerhalb des gültigen Bereichs: {}",
+            index
+        )));
+    }
+    Ok(pos as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::Parser;
+
+    type Outcome = (Interpreter, std::result::Result<(), String>);
+
+    fn run(code: &str) -> Outcome {
+        run_path("<test>", code)
+    }
+
+    // Führt ein Programm aus; ein nicht abgefangener Fehler kommt samt Traceback zurück
+    fn run_path(path: &str, code: &str) -> Outcome {
+        let mut interpreter = Interpreter::new();
+        let file = interpreter.add_source(path, code);
+        let ast = Parser::from_source(code, file).parse();
+        let result = match interpreter.interpret(&ast) {
+            Ok(_) => Ok(()),
+            Err(exception) => Err(interpreter.describe_exception(exception)),
+        };
+        (interpreter, result)
+    }
+
+    // Wert einer globalen Variable nach einem fehlerfreien Lauf
+    fn global(code: &str, name: &str) -> Value {
+        let (interpreter, result) = run(code);
+        if let Err(message) = result {
+            panic!("Programm ist fehlgeschlagen:\n{}", message);
+        }
+        let value = interpreter.environment.borrow().get(name);
+        value.unwrap_or_else(|| panic!("'{}' ist nicht definiert", name))
+    }
+
+    fn error(code: &str) -> String {
+        match run(code).1 {
+            Err(message) => message,
+            Ok(()) => panic!("Fehler erwartet"),
+        }
+    }
+
+    fn string(value: &str) -> Value {
+        Value::String(value.to_string())
+    }
+
+    fn booleans(values: &[bool]) -> Value {
+        Value::list(values.iter().map(|b| Value::Boolean(*b)).collect())
+    }
+
+    fn numbers(values: &[f64]) -> Value {
+        Value::list(values.iter().map(|n| Value::Number(*n)).collect())
+    }
+
+    #[test]
+    fn functions_are_values() {
+        let code = "
+def add(a, b):
+    return a + b
+g = add
+same = g == add
+fs = [add]
+d = {\"f\": add}
+r = [g(1, 2), fs[0](3, 4), d[\"f\"](5, 6)]
+";
+        assert_eq!(global(code, "same"), Value::Boolean(true));
+        assert_eq!(global(code, "r"), numbers(&[3.0, 7.0, 11.0]));
+    }
+
+    #[test]
+    fn calls_on_arbitrary_expressions() {
+        let code = "
+def adder(n):
+    def add(x):
+        return x + n
+    return add
+r = adder(10)(1)
+";
+        assert_eq!(global(code, "r"), Value::Number(11.0));
+    }
+
+    #[test]
+    fn calling_a_non_function_is_a_type_error() {
+        let message = error("x = 5\nx(1)\n");
+        let expected = "TypeError: Wert vom Typ 'number' ist nicht aufrufbar";
+        assert!(message.ends_with(expected), "{}", message);
+    }
+
+    #[test]
+    fn lambdas_as_arguments_and_values() {
+        let code = "
+add = lambda x, y: x + y
+words = sorted([\"ccc\", \"a\", \"bb\"], key=lambda w: len(w))
+curried = lambda x: lambda y: x - y
+r = [add(1, 2), curried(10)(3)]
+";
+        assert_eq!(global(code, "r"), numbers(&[3.0, 7.0]));
+        let words = Value::list(vec![string("a"), string("bb"), string("ccc")]);
+        assert_eq!(global(code, "words"), words);
+    }
+
+    #[test]
+    fn lambdas_see_later_changes_to_their_scope() {
+        assert_eq!(global("n = 1\nf = lambda: n\nn = 2\nr = f()\n", "r"), Value::Number(2.0));
+    }
+
+    #[test]
+    fn lambda_arity_is_checked() {
+        let message = error("f = lambda a, b: a\nf(1)\n");
+        let expected = "TypeError: Falsche Anzahl von Argumenten für Funktion '<lambda>'";
+        assert!(message.ends_with(expected), "{}", message);
+    }
+
+    #[test]
+    fn augmented_assignment_on_variables() {
+        let code = "
+a = 1
+a += 2
+a -= 1
+a *= 5
+a /= 2
+a //= 2
+a %= 3
+a **= 3
+s = \"a\"
+s += \"b\"
+s *= 2
+";
+        assert_eq!(global(code, "a"), Value::Number(8.0));
+        assert_eq!(global(code, "s"), string("abab"));
+    }
+
+    #[test]
+    fn augmented_assignment_on_subscripts_and_attributes() {
+        let code = "
+class Counter:
+    def __init__(self):
+        self.n = 1
+c = Counter()
+c.n += 4
+n = c.n
+v = [1, 2]
+v[0] += 10
+";
+        assert_eq!(global(code, "n"), Value::Number(5.0));
+        assert_eq!(global(code, "v"), numbers(&[11.0, 2.0]));
+    }
+
+    #[test]
+    fn augmented_assignment_evaluates_the_target_once() {
+        let code = "
+calls = []
+def index():
+    calls.append(1)
+    return 0
+w = [5]
+w[index()] += 1
+count = len(calls)
+";
+        assert_eq!(global(code, "w"), numbers(&[6.0]));
+        assert_eq!(global(code, "count"), Value::Number(1.0));
+    }
+
+    #[test]
+    fn tuple_and_nested_unpacking() {
+        let code = "
+x, y = 1, 2
+x, y = y, x
+(p, (q, r)) = (1, (2, 3))
+[s, [t, u]] = [4, [5, 6]]
+";
+        assert_eq!(global(code, "x"), Value::Number(2.0));
+        assert_eq!(global(code, "y"), Value::Number(1.0));
+        assert_eq!(global(code, "r"), Value::Number(3.0));
+        assert_eq!(global(code, "u"), Value::Number(6.0));
+    }
+
+    #[test]
+    fn starred_and_chained_assignment() {
+        let code = "first, *rest = [1, 2, 3]\n*init, last = \"abc\"\nm = n = 5\n";
+        assert_eq!(global(code, "first"), Value::Number(1.0));
+        assert_eq!(global(code, "rest"), numbers(&[2.0, 3.0]));
+        assert_eq!(global(code, "init"), Value::list(vec![string("a"), string("b")]));
+        assert_eq!(global(code, "last"), string("c"));
+        assert_eq!(global(code, "m"), Value::Number(5.0));
+        assert_eq!(global(code, "n"), Value::Number(5.0));
+    }
+
+    #[test]
+    fn unpacking_arity_mismatches() {
+        let cases = [
+            ("a, b = [1, 2, 3]", "Falsche Anzahl von Werten", "(erwartet 2, erhalten 3)"),
+            ("a, b, c = [1]", "Falsche Anzahl von Werten", "(erwartet 3, erhalten 1)"),
+            ("a, *b, c = [1]", "Nicht genug Werte", "(erwartet mindestens 2, erhalten 1)"),
+        ];
+        for (code, start, detail) in cases {
+            let message = error(&format!("{}\n", code));
+            let expected = format!("ValueError: {} zum Entpacken {}", start, detail);
+            assert!(message.ends_with(&expected), "{}", message);
+        }
+    }
+
+    #[test]
+    fn operator_precedence() {
+        let code = "
+r = [-2 ** 2, 2 ** 3 ** 2, 2 ** -1, 1 | 6 & 3, 6 ^ 3 | 8, 1 << 2 + 1, 7 // 2 * 2, ~5 + 1]
+";
+        let expected = numbers(&[-4.0, 512.0, 0.5, 3.0, 13.0, 8.0, 6.0, -5.0]);
+        assert_eq!(global(code, "r"), expected);
+        assert!(error("x = ~(2 ** 70)\n").ends_with("ist zu groß für eine ganze Zahl"));
+    }
+
+    #[test]
+    fn chained_comparisons_evaluate_the_middle_once() {
+        let code = "
+calls = []
+def middle():
+    calls.append(1)
+    return 5
+inside = 0 < middle() < 10
+count = len(calls)
+r = [1 < 2 < 3, 3 > 2 > 2, 1 == 1 != 2]
+";
+        assert_eq!(global(code, "inside"), Value::Boolean(true));
+        assert_eq!(global(code, "count"), Value::Number(1.0));
+        assert_eq!(global(code, "r"), booleans(&[true, false, true]));
+    }
+
+    #[test]
+    fn membership_and_identity() {
+        let code = "
+xs = [1]
+ys = xs
+r = [2 in [1, 2], \"b\" in \"abc\", 3 not in (1, 2), \"k\" in {\"k\": 1}, 1 in {\"k\": 1}]
+s = [xs is ys, xs is [1], xs is not [1], None is None]
+";
+        assert_eq!(global(code, "r"), booleans(&[true, true, true, true, false]));
+        assert_eq!(global(code, "s"), booleans(&[true, false, true, true]));
+    }
+
+    #[test]
+    fn ordering_of_strings_sequences_and_booleans() {
+        let code = "
+r = [\"ab\" < \"b\", [1, 2] < [1, 2, 0], (1, \"b\") < (1, \"c\"), True < 2, 1 <= 1.0]
+words = sorted([\"b\", \"a\", \"c\"])
+lists = sorted([[1, 2], [1], [0, 5]])
+small = min(\"b\", \"a\")
+large = max([(1, \"b\"), (1, \"c\")])
+";
+        assert_eq!(global(code, "r"), booleans(&[true, true, true, true, true]));
+        assert_eq!(global(code, "words"), Value::list(vec![string("a"), string("b"), string("c")]));
+        let lists = Value::list(vec![numbers(&[0.0, 5.0]), numbers(&[1.0]), numbers(&[1.0, 2.0])]);
+        assert_eq!(global(code, "lists"), lists);
+        assert_eq!(global(code, "small"), string("a"));
+        assert_eq!(global(code, "large"), Value::Tuple(vec![Value::Number(1.0), string("c")]));
+    }
+
+    #[test]
+    fn incomparable_types_are_type_errors() {
+        let cases = [
+            ("1 < \"a\"", "'number' und 'str'"),
+            ("sorted([1, \"a\"])", "'str' und 'number'"),
+            ("{} < {}", "'dict' und 'dict'"),
+        ];
+        for (code, types) in cases {
+            let message = error(&format!("x = {}\n", code));
+            let expected = format!("TypeError: '<' wird zwischen {} nicht unterstützt", types);
+            assert!(message.ends_with(&expected), "{}", message);
+        }
+    }
+
+    #[test]
+    fn sorting_survives_inconsistent_comparisons() {
+        let code = "
+import random
+import math
+class Coin:
+    def __lt__(self, other):
+        return random.random() < 0.5
+coins = len(sorted([Coin() for i in [0] * 300]))
+xs = [Coin() for i in [0] * 50]
+xs.sort()
+listed = len(xs)
+with_nan = len(sorted([3, math.nan, 1, 2, math.nan]))
+";
+        assert_eq!(global(code, "coins"), Value::Number(300.0));
+        assert_eq!(global(code, "listed"), Value::Number(50.0));
+        assert_eq!(global(code, "with_nan"), Value::Number(5.0));
+    }
+
+    #[test]
+    fn sorting_is_stable_in_both_directions() {
+        let code = "
+pairs = [(1, \"b\"), (0, \"z\"), (1, \"a\")]
+up = [p[1] for p in sorted(pairs, key=lambda p: p[0])]
+down = [p[1] for p in sorted(pairs, key=lambda p: p[0], reverse=True)]
+";
+        let strings = |items: &[&str]| Value::list(items.iter().map(|s| string(s)).collect());
+        assert_eq!(global(code, "up"), strings(&["z", "b", "a"]));
+        assert_eq!(global(code, "down"), strings(&["b", "a", "z"]));
+    }
+
+    #[test]
+    fn errors_in_comparisons_abort_the_sort() {
+        let code = "
+class Bad:
+    def __lt__(self, other):
+        return [][0]
+sorted([Bad(), Bad(), Bad()])
+";
+        assert!(error(code).ends_with("IndexError: Index außerhalb des gültigen Bereichs: 0"));
+    }
+
+    #[test]
+    fn conditional_expressions() {
+        let code = "
+r = [1 if True else 2, \"a\" if 0 else \"b\", 1 if False else 2 if False else 3]
+lazy = [] if True else [][5]
+low = True or False if False else \"x\"
+";
+        let r = Value::list(vec![Value::Number(1.0), string("b"), Value::Number(3.0)]);
+        assert_eq!(global(code, "r"), r);
+        assert_eq!(global(code, "lazy"), Value::list(Vec::new()));
+        // Bindet schwächer als `or`
+        assert_eq!(global(code, "low"), string("x"));
+    }
+
+    const POINT: &str = "
+class Point:
+    dims = 2
+    def __init__(self, x, y):
+        self.x = x
+        self.y = y
+    def total(self):
+        return self.x + self.y
+    def __str__(self):
+        return \"P(\" + str(self.x) + \", \" + str(self.y) + \")\"
+";
+
+    #[test]
+    fn classes_with_fields_methods_and_class_attributes() {
+        let code = format!(
+            "{}\np = Point(1, 2)\np.z = 3\nbound = p.total\n{}",
+            POINT, "r = [p.total(), bound(), p.dims, Point.dims, p.z]\n"
+        );
+        assert_eq!(global(&code, "r"), numbers(&[3.0, 3.0, 2.0, 2.0, 3.0]));
+    }
+
+    #[test]
+    fn instances_print_with_their_str_method() {
+        let code = format!("{}\np = Point(1, 2)\ns = str(p)\nc = str(Point)\n", POINT);
+        assert_eq!(global(&code, "s"), string("P(1, 2)"));
+        assert_eq!(global(&code, "c"), string("<class 'Point'>"));
+    }
+
+    #[test]
+    fn instances_are_distinct_objects() {
+        let code = format!("{}\nsame = Point(1, 2) == Point(1, 2)\n", POINT);
+        assert_eq!(global(&code, "same"), Value::Boolean(false));
+    }
+
+    #[test]
+    fn constructor_arity_and_missing_attributes() {
+        let message = error(&format!("{}\nPoint(1)\n", POINT));
+        let expected = "TypeError: Falsche Anzahl von Argumenten für Funktion '__init__'";
+        assert!(message.ends_with(expected), "{}", message);
+        let message = error(&format!("{}\nPoint(1, 2).w\n", POINT));
+        let expected = "AttributeError: 'Point'-Objekt hat kein Attribut 'w'";
+        assert!(message.ends_with(expected), "{}", message);
+    }
+
+    const SHAPES: &str = "
+class Shape:
+    kind = \"shape\"
+    def __init__(self, name):
+        self.name = name
+    def area(self):
+        return 0
+    def describe(self):
+        return self.name + \": \" + str(self.area())
+
+class Square(Shape):
+    def __init__(self, side):
+        super().__init__(\"square\")
+        self.side = side
+    def area(self):
+        return self.side * self.side
+
+class Cube(Square):
+    def area(self):
+        return 6 * super().area()
+";
+
+    #[test]
+    fn attributes_and_methods_are_found_through_the_base() {
+        let code = format!("{}\nc = Cube(2)\nkind = c.kind\nname = c.name\n", SHAPES);
+        assert_eq!(global(&code, "kind"), string("shape"));
+        assert_eq!(global(&code, "name"), string("square"));
+    }
+
+    #[test]
+    fn super_init_runs_the_base_constructor() {
+        let code = format!("{}\ns = Square(3)\nresult = [s.name, s.side]\n", SHAPES);
+        assert_eq!(
+            global(&code, "result"),
+            Value::list(vec![string("square"), Value::Number(3.0)])
+        );
+    }
+
+    #[test]
+    fn overriding_methods_dispatch_on_the_instance() {
+        let code = format!(
+            "{}\nbase = Shape(\"blob\").describe()\nsquare = Square(3).describe()\n{}",
+            SHAPES, "cube = Cube(2).describe()\n"
+        );
+        assert_eq!(global(&code, "base"), string("blob: 0"));
+        // describe() ist nur in Shape definiert und ruft die überschriebene area() auf
+        assert_eq!(global(&code, "square"), string("square: 9"));
+        // Cube.area() erreicht über super() die Methode von Square
+        assert_eq!(global(&code, "cube"), string("square: 24"));
+    }
+
+    #[test]
+    fn explicit_two_argument_super() {
+        let code = "
+class A:
+    def name(self):
+        return \"A\"
+class B(A):
+    def name(self):
+        return \"B\" + super(B, self).name()
+result = B().name()
+";
+        assert_eq!(global(code, "result"), string("BA"));
+    }
+
+    #[test]
+    fn isinstance_follows_the_class_chain() {
+        let code = format!(
+            "{}\nc = Cube(1)\ns = Shape(\"x\")\n\
+             result = [isinstance(c, Cube), isinstance(c, Square), isinstance(c, Shape), \
+             isinstance(s, Square), isinstance(3, Shape), \
+             isinstance(s, (Cube, Shape)), isinstance(s, (Cube, Square))]\n",
+            SHAPES
+        );
+        let expected = [true, true, true, false, false, true, false];
+        assert_eq!(global(&code, "result"), booleans(&expected));
+    }
+
+    #[test]
+    fn issubclass_follows_the_class_chain() {
+        let code = format!(
+            "{}\nresult = [issubclass(Cube, Shape), issubclass(Square, Square), \
+             issubclass(Shape, Square), issubclass(Square, Cube)]\n",
+            SHAPES
+        );
+        assert_eq!(global(&code, "result"), booleans(&[true, true, false, false]));
+    }
+
+    #[test]
+    fn issubclass_rejects_non_classes() {
+        assert!(error("x = issubclass(1, 2)\n").contains("TypeError"));
+    }
+
+    #[test]
+    fn generator_expressions_are_lazy_and_single_use() {
+        let code = "
+g = (x * 2 for x in [1, 2, 3] if x != 2)
+first = sorted(g)
+second = sorted(g)
+";
+        assert_eq!(global(code, "first"), numbers(&[2.0, 6.0]));
+        assert_eq!(global(code, "second"), Value::list(Vec::new()));
+    }
+
+    #[test]
+    fn comprehensions_with_nested_clauses() {
+        let code = "
+pairs = [(x, y) for x in [1, 2] for y in [1, 2] if x != y]
+squares = {x: x * x for x in [1, 2]}
+";
+        let pair = |x: f64, y: f64| Value::Tuple(vec![Value::Number(x), Value::Number(y)]);
+        assert_eq!(global(code, "pairs"), Value::list(vec![pair(1.0, 2.0), pair(2.0, 1.0)]));
+        let mut squares = Dict::new();
+        squares.insert(Value::Number(1.0), Value::Number(1.0)).unwrap();
+        squares.insert(Value::Number(2.0), Value::Number(4.0)).unwrap();
+        assert_eq!(global(code, "squares"), Value::dict(squares));
+    }
+
+    #[test]
+    fn resuming_a_running_generator_is_a_value_error() {
+        let message = error("g = (y for y in [1, 2] if sorted(g))
+sorted(g)
+");
+        assert!(message.contains("ValueError: Generator läuft bereits"), "{}", message);
+    }
+
+    #[test]
+    fn lists_and_dicts_are_shared_references() {
+        let code = "
+def add(items, item):
+    items.append(item)
+xs = [1]
+alias = xs
+add(alias, 2)
+copy = xs.copy()
+copy.append(3)
+d = {}
+other = d
+other[\"k\"] = xs
+same = [alias is xs, copy is xs, d is other]
+";
+        assert_eq!(global(code, "xs"), numbers(&[1.0, 2.0]));
+        assert_eq!(global(code, "same"), booleans(&[true, false, true]));
+    }
+
+    #[test]
+    fn list_and_dict_methods() {
+        let code = "
+xs = [3, 1, 2]
+xs.sort()
+xs.insert(0, 0)
+popped = xs.pop()
+xs.extend([7, 8])
+xs.remove(7)
+d = {\"a\": 1}
+d.update({\"b\": 2})
+value = d.get(\"c\", 5)
+d.setdefault(\"c\", 3)
+keys = d.keys()
+";
+        assert_eq!(global(code, "xs"), numbers(&[0.0, 1.0, 2.0, 8.0]));
+        assert_eq!(global(code, "popped"), Value::Number(3.0));
+        assert_eq!(global(code, "value"), Value::Number(5.0));
+        assert_eq!(
+            global(code, "keys"),
+            Value::list(vec![string("a"), string("b"), string("c")])
+        );
+    }
+
+    #[test]
+    fn list_remove_survives_mutation_from_eq() {
+        let code = "
+xs = [1, 2]
+class Clearing:
+    def __eq__(self, other):
+        xs.clear()
+        return True
+try:
+    xs.remove(Clearing())
+except RuntimeError as e:
+    message = str(e)
+ys = [5, 6, 7]
+class Shifting:
+    def __eq__(self, other):
+        if other == 6:
+            ys.insert(0, 0)
+            return True
+        return False
+ys.remove(Shifting())
+";
+        assert_eq!(global(code, "message"), string("Liste wurde während list.remove() verändert"));
+        assert_eq!(global(code, "ys"), numbers(&[0.0, 5.0, 7.0]));
+    }
+
+    #[test]
+    fn self_containing_containers_print_with_ellipsis() {
+        let code = "
+xs = [1]
+xs.append(xs)
+d = {\"a\": 1}
+d[\"self\"] = d
+d[\"list\"] = [d]
+text = [str(xs), str(d)]
+";
+        assert_eq!(
+            global(code, "text"),
+            Value::list(vec![
+                string("[1, [...]]"),
+                string("{a: 1, self: {...}, list: [{...}]}"),
+            ])
+        );
+    }
+
+    #[test]
+    fn self_containing_containers_compare_without_recursing() {
+        let code = "
+xs = [1]
+xs.append(xs)
+ys = [1]
+ys.append(ys)
+d = {}
+d[\"self\"] = d
+result = [xs == xs, xs == ys, xs in [xs], d == d, [1, [2]] == [1, [3]]]
+";
+        assert_eq!(global(code, "result"), booleans(&[true, true, true, true, false]));
+    }
+
+    #[test]
+    fn string_methods_are_unicode_aware() {
+        let code = "
+result = [
+    \"straße\".upper(),
+    \"ÄRGER\".lower(),
+    \"héllo wörld\".title(),
+    \"äöü\".isalpha(),
+    \"ü\".center(5, \"*\"),
+]
+";
+        assert_eq!(
+            global(code, "result"),
+            Value::list(vec![
+                string("STRASSE"),
+                string("ärger"),
+                string("Héllo Wörld"),
+                Value::Boolean(true),
+                string("**ü**"),
+            ])
+        );
+    }
+
+    #[test]
+    fn casefold_applies_full_case_folding() {
+        let code = "
+result = [
+    \"Straße\".casefold(),
+    \"GROẞ\".casefold(),
+    \"ﬁnden\".casefold(),
+    \"ΟΔΟΣ\".casefold() == \"οδος\".casefold(),
+    \"ᾈ\".casefold(),
+    \"ꭰᏸ\".casefold(),
+    \"ŉ\".casefold(),
+]
+";
+        assert_eq!(
+            global(code, "result"),
+            Value::list(vec![
+                string("strasse"),
+                string("gross"),
+                string("finden"),
+                Value::Boolean(true),
+                string("ἀι"),
+                string("ᎠᏰ"),
+                string("ʼn"),
+            ])
+        );
+    }
+
+    #[test]
+    fn digit_tests_cover_unicode_digits() {
+        // Arabisch-indische Ziffern, hochgestellte Zwei, Bruch, Devanagari
+        let code = "
+samples = [\"١٢\", \"²\", \"½\", \"४२\", \"12\", \"1a\", \"\"]
+digit = [s.isdigit() for s in samples]
+decimal = [s.isdecimal() for s in samples]
+numeric = [s.isnumeric() for s in samples]
+";
+        let digit = [true, true, false, true, true, false, false];
+        let decimal = [true, false, false, true, true, false, false];
+        let numeric = [true, true, true, true, true, false, false];
+        assert_eq!(global(code, "digit"), booleans(&digit));
+        assert_eq!(global(code, "decimal"), booleans(&decimal));
+        assert_eq!(global(code, "numeric"), booleans(&numeric));
+    }
+
+    #[test]
+    fn padding_widths() {
+        let code = "
+result = [
+    \"-42\".zfill(6),
+    \"ab\".ljust(4, \".\"),
+    \"ab\".rjust(1),
+    \"{:*^7}\".format(\"mid\"),
+    \"{:05}\".format(-3),
+    \"ab\".center(5, \"*\"),
+    \"ab\".center(7, \"*\"),
+    \"abc\".center(6, \"*\"),
+    \"{:*^5}\".format(\"ab\"),
+]
+";
+        assert_eq!(
+            global(code, "result"),
+            Value::list(vec![
+                string("-00042"),
+                string("ab.."),
+                string("ab"),
+                string("**mid**"),
+                string("-0003"),
+                string("**ab*"),
+                string("***ab**"),
+                string("*abc**"),
+                string("*ab**"),
+            ])
+        );
+    }
+
+    #[test]
+    fn oversized_padding_is_an_overflow_error() {
+        let calls = [
+            "\"a\".zfill(2 ** 62)",
+            "\"a\".center(2 ** 62)",
+            "\"{:99999999999}\".format(1)",
+        ];
+        for call in calls {
+            let message = error(&format!("x = {}\n", call));
+            assert!(message.contains("OverflowError"), "{}: {}", call, message);
+        }
+    }
+
+    #[test]
+    fn traceback_lists_every_frame() {
+        let code = "
+def inner(x):
+    return x[10]
+def outer():
+    return inner([1])
+outer()
+";
+        let expected = "\
+Traceback (jüngster Aufruf zuletzt):
+  Datei \"<test>\", Zeile 6, in <module>
+    outer()
+  Datei \"<test>\", Zeile 5, in outer
+    return inner([1])
+  Datei \"<test>\", Zeile 3, in inner
+    return x[10]
+IndexError: Index außerhalb des gültigen Bereichs: 10";
+        assert_eq!(error(code), expected);
+    }
+
+    #[test]
+    fn traceback_includes_lambda_frames() {
+        let code = "h = lambda x: x[10]\ndef k():\n    return h([1])\nk()\n";
+        let message = error(code);
+        let frames: Vec<&str> =
+            message.lines().filter(|line| line.starts_with("  Datei")).collect();
+        assert_eq!(
+            frames,
+            [
+                "  Datei \"<test>\", Zeile 4, in <module>",
+                "  Datei \"<test>\", Zeile 3, in k",
+                "  Datei \"<test>\", Zeile 1, in <lambda>",
+            ]
+        );
+    }
+
+    #[test]
+    fn traceback_of_a_builtin_error_inside_a_lambda() {
+        let code = "f = lambda: sorted(1)\nf()\n";
+        let message = error(code);
+        assert!(message.contains("Zeile 1, in <lambda>\n    f = lambda: sorted(1)"), "{}", message);
+    }
+
+    #[test]
+    fn caught_exceptions_leave_no_traceback() {
+        let code = "
+def fail():
+    return [][1]
+try:
+    fail()
+except IndexError:
+    caught = True
+fail()
+";
+        let message = error(code);
+        assert_eq!(message.matches("Traceback").count(), 1);
+        assert!(message.contains("Zeile 8, in <module>"), "{}", message);
+    }
+
+    #[test]
+    fn random_bounds_may_span_all_of_i64() {
+        let code = "
+import random
+random.seed(7)
+low = -2 ** 63
+high = 2 ** 63 - 1024
+xs = [random.randint(low, high) for i in [0] * 100]
+ys = [random.randrange(low, high) for i in [0] * 100]
+inside = len([x for x in xs + ys if low <= x and x <= high]) == 200
+pinned = random.randint(low, low)
+stepped = random.randrange(high, low, -2 ** 62)
+";
+        assert_eq!(global(code, "inside"), Value::Boolean(true));
+        assert_eq!(global(code, "pinned"), Value::Number(-(2f64.powi(63))));
+        let stepped = global(code, "stepped");
+        assert!(matches!(stepped, Value::Number(n) if n > -(2f64.powi(63)) && n <= 2f64.powi(63)));
+    }
+
+    #[test]
+    fn math_functions_and_constants() {
+        let code = "
+import math
+from math import sqrt, pi
+r = [sqrt(16), math.floor(-1.5), math.ceil(1.2), math.log(math.e), math.factorial(5)]
+close = math.isclose(math.sin(pi), 0, abs_tol=0.000000001)
+";
+        assert_eq!(global(code, "r"), numbers(&[4.0, -2.0, 2.0, 1.0, 120.0]));
+        assert_eq!(global(code, "close"), Value::Boolean(true));
+    }
+
+    #[test]
+    fn random_is_reproducible_after_seeding() {
+        let code = "
+import random
+def draw():
+    return [random.random(), random.randint(1, 6), random.choice([1, 2, 3])]
+random.seed(42)
+a = draw()
+random.seed(42)
+same = a == draw()
+xs = [1, 2, 3, 4]
+random.shuffle(xs)
+xs = sorted(xs)
+";
+        assert_eq!(global(code, "same"), Value::Boolean(true));
+        assert_eq!(global(code, "xs"), numbers(&[1.0, 2.0, 3.0, 4.0]));
+    }
+
+    #[test]
+    fn monotonic_clock_advances_during_sleep() {
+        let code = "
+import time
+t0 = time.monotonic()
+time.sleep(0.01)
+ok = time.monotonic() - t0 >= 0.01
+";
+        assert_eq!(global(code, "ok"), Value::Boolean(true));
+    }
+
+    #[test]
+    fn native_module_errors() {
+        let cases = [
+            ("math.sqrt(-1)", "ValueError: Argument außerhalb des mathematischen"),
+            ("time.sleep(-1)", "ValueError: time.sleep(): Dauer muss eine nicht negative"),
+            ("random.choice([])", "IndexError: random.choice(): leere Sequenz"),
+            ("math.nope(1)", "AttributeError: Modul 'math' hat kein Attribut 'nope'"),
+        ];
+        for (call, expected) in cases {
+            let message = error(&format!("import math\nimport random\nimport time\n{}\n", call));
+            assert!(message.contains(expected), "{}", message);
+        }
+    }
+
+    #[test]
+    fn gcd_of_the_smallest_integer() {
+        let code = "import math\na = math.gcd(-2 ** 63, 0)\nb = math.gcd(-2 ** 63, 6)\n";
+        assert_eq!(global(code, "a"), Value::Number(2f64.powi(63)));
+        assert_eq!(global(code, "b"), Value::Number(2.0));
+    }
+
+    #[test]
+    fn largest_factorial() {
+        let code = "import math\nf = math.factorial(170)\n";
+        assert!(matches!(global(code, "f"), Value::Number(n) if n.is_finite() && n > 7e306));
+    }
+
+    #[test]
+    fn integers_beyond_i64_are_overflow_errors() {
+        let calls = [
+            "random.randint(0, 2 ** 80)",
+            "random.randint(-2 ** 63, 2 ** 63)",
+            "random.randrange(-2 ** 64)",
+            "math.gcd(2 ** 63)",
+            "math.factorial(2 ** 70)",
+            "math.factorial(171)",
+            "math.factorial(1000000000000)",
+            "time.sleep(10 ** 20)",
+            "[1, 2].pop(2 ** 70)",
+            "[1, 2].insert(-2 ** 70, 0)",
+        ];
+        for call in calls {
+            let message = error(&format!("import random\nimport math\nimport time\n{}\n", call));
+            assert!(message.contains("OverflowError"), "{}: {}", call, message);
+        }
+    }
+
+    #[test]
+    fn missing_methods_are_attribute_errors() {
+        let code = format!("{}\nSquare(1).volume()\n", SHAPES);
+        assert!(error(&code).contains("AttributeError"));
+        let expected = "AttributeError: 'number' hat kein Attribut 'foo'";
+        assert!(error("x = (5).foo\n").ends_with(expected));
+    }
+
+    #[test]
+    fn methods_on_builtin_values() {
+        let code = "
+r = [\"abc\".upper(), (1, 2, 1).count(1), (1, 2).index(2), {\"k\": 1}.get(\"k\")]
+chained = \"a-b\".replace(\"-\", \"+\").upper()
+xs = [1]
+xs.append(2)
+";
+        let r = vec![string("ABC"), Value::Number(2.0), Value::Number(1.0), Value::Number(1.0)];
+        assert_eq!(global(code, "r"), Value::list(r));
+        assert_eq!(global(code, "chained"), string("A+B"));
+        assert_eq!(global(code, "xs"), numbers(&[1.0, 2.0]));
+    }
+
+    const VECTOR: &str = "
+class V:
+    def __init__(self, x):
+        self.x = x
+    def __add__(self, other):
+        return V(self.x + other.x)
+    def __sub__(self, other):
+        return V(self.x - other.x)
+    def __mul__(self, k):
+        return V(self.x * k)
+    def __rmul__(self, k):
+        return V(self.x * k * 10)
+    def __neg__(self):
+        return V(-self.x)
+    def __eq__(self, other):
+        return self.x == other.x
+    def __lt__(self, other):
+        return self.x < other.x
+    def __len__(self):
+        return 3
+    def __getitem__(self, i):
+        return i * 2
+    def __iter__(self):
+        return [self.x, self.x + 1]
+    def __contains__(self, y):
+        return y == 42
+    def __repr__(self):
+        return \"V\" + str(self.x)
+";
+
+    #[test]
+    fn arithmetic_dunder_methods() {
+        let code = format!(
+            "{}\nr = [(V(1) + V(2)).x, (V(5) - V(2)).x, (V(2) * 3).x, (3 * V(2)).x, (-V(1)).x]\n",
+            VECTOR
+        );
+        assert_eq!(global(&code, "r"), numbers(&[3.0, 3.0, 6.0, 60.0, -1.0]));
+    }
+
+    #[test]
+    fn comparison_dunder_methods() {
+        let code = format!(
+            "{}\nr = [V(1) == V(1), V(1) != V(2), V(1) < V(2)]\ns = str(sorted([V(3), V(1)]))\n",
+            VECTOR
+        );
+        assert_eq!(global(&code, "r"), booleans(&[true, true, true]));
+        assert_eq!(global(&code, "s"), string("[V1, V3]"));
+    }
+
+    #[test]
+    fn container_dunder_methods() {
+        let code = format!(
+            "{}\nr = [len(V(0)), V(0)[4]]\nitems = [x for x in V(4)]\n{}",
+            VECTOR, "found = [42 in V(0), 1 in V(0)]\n"
+        );
+        assert_eq!(global(&code, "r"), numbers(&[3.0, 8.0]));
+        assert_eq!(global(&code, "items"), numbers(&[4.0, 5.0]));
+        assert_eq!(global(&code, "found"), booleans(&[true, false]));
+    }
+
+    #[test]
+    fn missing_dunder_methods_are_type_errors() {
+        let message = error("class A:\n    x = 1\nA() + 1\n");
+        let expected = "TypeError: Ungültige Operandentypen für '+': 'object' und 'number'";
+        assert!(message.ends_with(expected), "{}", message);
+    }
+
+    #[test]
+    fn try_except_else_finally_order() {
+        let code = "
+log = []
+def divide(x):
+    try:
+        log.append(\"try\")
+        r = 10 / x
+    except ZeroDivisionError as e:
+        log.append(str(e))
+        r = -1
+    else:
+        log.append(\"else\")
+    finally:
+        log.append(\"finally\")
+    return r
+r = [divide(2), divide(0)]
+";
+        assert_eq!(global(code, "r"), numbers(&[5.0, -1.0]));
+        let log = ["try", "else", "finally", "try", "Division durch Null", "finally"];
+        assert_eq!(global(code, "log"), Value::list(log.iter().map(|s| string(s)).collect()));
+    }
+
+    #[test]
+    fn finally_runs_on_return() {
+        let code = "
+log = []
+def f():
+    try:
+        return 1
+    finally:
+        log.append(2)
+r = f()
+";
+        assert_eq!(global(code, "r"), Value::Number(1.0));
+        assert_eq!(global(code, "log"), numbers(&[2.0]));
+    }
+
+    #[test]
+    fn builtin_errors_are_caught_through_the_hierarchy() {
+        let code = "
+caught = []
+try:
+    {}[\"k\"]
+except LookupError as e:
+    caught.append(str(e))
+try:
+    try:
+        [][0]
+    except KeyError:
+        caught.append(\"falsch\")
+except IndexError:
+    caught.append(\"außen\")
+try:
+    try:
+        1 / 0
+    except ZeroDivisionError:
+        raise
+except ArithmeticError:
+    caught.append(\"erneut\")
+try:
+    [][1]
+except (KeyError, IndexError):
+    caught.append(\"tupel\")
+";
+        let caught = ["Schlüssel nicht gefunden: k", "außen", "erneut", "tupel"];
+        assert_eq!(global(code, "caught"), Value::list(caught.iter().map(|s| string(s)).collect()));
+    }
+
+    #[test]
+    fn user_defined_exceptions() {
+        let code = "
+class MyError(ValueError):
+    pass_ = 0
+try:
+    raise MyError(\"boom\", 2)
+except ValueError as e:
+    r = [isinstance(e, MyError), isinstance(e, Exception)]
+    args = e.args
+";
+        assert_eq!(global(code, "r"), booleans(&[true, true]));
+        assert_eq!(global(code, "args"), Value::Tuple(vec![string("boom"), Value::Number(2.0)]));
+        assert!(error("class E(Exception):\n    x = 1\nraise E(\"a\")\n").ends_with("E: a"));
+    }
+
+    #[test]
+    fn raising_a_non_exception_is_a_type_error() {
+        let expected = "TypeError: Ausnahmen müssen von BaseException erben, nicht 'number'";
+        assert!(error("raise 5\n").ends_with(expected));
+    }
+
+    #[test]
+    fn assert_messages() {
+        let code = "
+x = 3
+assert x == 3
+assert x > 1, \"x zu klein\"
+messages = []
+try:
+    assert x < 2, \"x zu groß: \" + str(x)
+except AssertionError as e:
+    messages.append(str(e))
+try:
+    assert x  <  2
+except AssertionError as e:
+    messages.append(str(e))
+";
+        let messages = Value::list(vec![string("x zu groß: 3"), string("x  <  2")]);
+        assert_eq!(global(code, "messages"), messages);
+    }
+
+    #[test]
+    fn disabled_assertions_are_not_evaluated() {
+        let code = "assert [][1], \"nie\"\ndone = True\n";
+        let mut interpreter = Interpreter::new();
+        interpreter.disable_assertions();
+        let file = interpreter.add_source("<test>", code);
+        let ast = Parser::from_source(code, file).parse();
+        assert!(interpreter.interpret(&ast).is_ok());
+        assert_eq!(interpreter.environment.borrow().get("done"), Some(Value::Boolean(true)));
+    }
+
+    // Ein eigenes temporäres Verzeichnis je Test
+    fn temp_dir(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("lumina-{}-{}", test, std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    // Legt die Dateien im temporären Verzeichnis an und führt `main.lum` aus
+    fn run_files(test: &str, files: &[(&str, &str)]) -> Outcome {
+        let dir = temp_dir(test);
+        for (name, code) in files {
+            std::fs::write(dir.join(name), code).unwrap();
+        }
+        let main = dir.join("main.lum");
+        let code = std::fs::read_to_string(&main).unwrap();
+        let outcome = run_path(&main.display().to_string(), &code);
+        std::fs::remove_dir_all(&dir).unwrap();
+        outcome
+    }
+
+    #[test]
+    fn import_forms_and_module_caching() {
+        let utils = "
+loads = []
+loads.append(1)
+def helper(x):
+    return x * 2
+";
+        let main = "
+import utils
+import utils as u
+from utils import helper, loads
+r = [utils.helper(1), u.helper(2), helper(3), len(loads)]
+same = u is utils
+name = utils.__name__
+";
+        let files = [("utils.lum", utils), ("main.lum", main)];
+        let (interpreter, result) = run_files("import", &files);
+        assert_eq!(result, Ok(()));
+        let get = |name: &str| interpreter.environment.borrow().get(name).unwrap();
+        assert_eq!(get("r"), numbers(&[2.0, 4.0, 6.0, 1.0]));
+        assert_eq!(get("same"), Value::Boolean(true));
+        assert_eq!(get("name"), string("utils"));
+    }
+
+    #[test]
+    fn circular_imports_are_import_errors() {
+        let files = [
+            ("a.lum", "import b\n"),
+            ("b.lum", "import a\n"),
+            ("main.lum", "import a\n"),
+        ];
+        let message = run_files("circular", &files).1.unwrap_err();
+        assert!(message.ends_with("ImportError: Zirkulärer Import: a -> b -> a"), "{}", message);
+    }
+
+    #[test]
+    fn missing_modules_and_names() {
+        let message = error("import gibt_es_nicht\n");
+        assert!(message.ends_with("ModuleNotFoundError: Kein Modul namens 'gibt_es_nicht'"));
+        let files = [("utils.lum", "x = 1\n"), ("main.lum", "from utils import y\n")];
+        let message = run_files("missing", &files).1.unwrap_err();
+        assert!(message.contains("ImportError"), "{}", message);
+    }
+
+    #[test]
+    fn reading_files_by_line_and_whole() {
+        let dir = temp_dir("read");
+        std::fs::write(dir.join("data.txt"), "eins\nzwei\ndrei").unwrap();
+        let code = format!(
+            "
+import fs
+path = \"{}\"
+f = fs.open(path)
+first = f.readline()
+rest = f.read()
+f.close()
+f = fs.open(path)
+lines = [line for line in f]
+f.close()
+",
+            dir.join("data.txt").display()
+        );
+        assert_eq!(global(&code, "first"), string("eins\n"));
+        assert_eq!(global(&code, "rest"), string("zwei\ndrei"));
+        let lines = Value::list(vec![string("eins\n"), string("zwei\n"), string("drei")]);
+        assert_eq!(global(&code, "lines"), lines);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn writing_appending_and_helpers() {
+        let dir = temp_dir("write");
+        let code = format!(
+            "
+import fs
+dir = \"{}\"
+f = fs.open(dir + \"/out.txt\", \"w\")
+f.write(\"a\")
+f.close()
+f = fs.open(dir + \"/out.txt\", \"a\")
+f.write(\"b\")
+f.close()
+fs.mkdir(dir + \"/sub\")
+listed = sorted(fs.listdir(dir))
+content = fs.open(dir + \"/out.txt\").read()
+fs.remove(dir + \"/out.txt\")
+exists = [fs.exists(dir + \"/out.txt\"), fs.exists(dir + \"/sub\")]
+",
+            dir.display()
+        );
+        let (interpreter, result) = run(&code);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, Ok(()));
+        let get = |name: &str| interpreter.environment.borrow().get(name).unwrap();
+        assert_eq!(get("listed"), Value::list(vec![string("out.txt"), string("sub")]));
+        assert_eq!(get("content"), string("ab"));
+        assert_eq!(get("exists"), booleans(&[false, true]));
+    }
+
+    #[test]
+    fn file_errors_are_catchable() {
+        let dir = temp_dir("errors");
+        let code = format!(
+            "
+import fs
+dir = \"{}\"
+caught = []
+try:
+    fs.open(dir + \"/fehlt.txt\")
+except FileNotFoundError:
+    caught.append(\"fehlt\")
+fs.open(dir + \"/da.txt\", \"w\").close()
+try:
+    fs.open(dir + \"/da.txt\", \"x\")
+except FileExistsError:
+    caught.append(\"existiert\")
+f = fs.open(dir + \"/da.txt\")
+f.close()
+try:
+    f.read()
+except ValueError as e:
+    caught.append(str(e))
+try:
+    fs.open(dir + \"/da.txt\", \"q\")
+except ValueError:
+    caught.append(\"modus\")
+",
+            dir.display()
+        );
+        let caught = global(&code, "caught");
+        std::fs::remove_dir_all(&dir).unwrap();
+        let expected = ["fehlt", "existiert", "E/A-Operation auf geschlossener Datei", "modus"];
+        assert_eq!(caught, Value::list(expected.iter().map(|s| string(s)).collect()));
+    }
+
+    #[test]
+    fn with_calls_enter_and_exit() {
+        let code = "
+log = []
+class Resource:
+    def __init__(self, name, swallow):
+        self.name = name
+        self.swallow = swallow
+    def __enter__(self):
+        log.append(\"enter \" + self.name)
+        return self.name
+    def __exit__(self, kind, value, tb):
+        log.append(\"exit \" + self.name + \" \" + str(kind == None) + \" \" + str(value))
+        return self.swallow
+with Resource(\"a\", False) as name:
+    log.append(\"body \" + name)
+with Resource(\"b\", True):
+    [][1]
+try:
+    with Resource(\"c\", False):
+        {}[\"x\"]
+except KeyError:
+    log.append(\"weitergereicht\")
+def early():
+    with Resource(\"d\", False):
+        return 5
+log.append(str(early()))
+";
+        let expected = [
+            "enter a",
+            "body a",
+            "exit a true null",
+            "enter b",
+            "exit b false Index außerhalb des gültigen Bereichs: 1",
+            "enter c",
+            "exit c false Schlüssel nicht gefunden: x",
+            "weitergereicht",
+            "enter d",
+            "exit d true null",
+            "5",
+        ];
+        assert_eq!(global(code, "log"), Value::list(expected.iter().map(|s| string(s)).collect()));
+    }
+
+    #[test]
+    fn with_closes_files() {
+        let dir = temp_dir("with");
+        let code = format!(
+            "
+import fs
+with fs.open(\"{}\", \"w\") as f:
+    f.write(\"x\")
+try:
+    f.write(\"y\")
+except ValueError:
+    closed = True
+",
+            dir.join("out.txt").display()
+        );
+        let closed = global(&code, "closed");
+        let content = std::fs::read_to_string(dir.join("out.txt")).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(closed, Value::Boolean(true));
+        assert_eq!(content, "x");
+    }
+
+    #[test]
+    fn with_holds_locks() {
+        let code = "
+import threading
+lock = threading.Lock()
+with lock as entered:
+    inside = lock.locked()
+    again = lock.acquire(False)
+try:
+    with lock:
+        [][1]
+except IndexError:
+    pass_ = 0
+after = [entered, inside, again, lock.locked(), lock.acquire(), lock.acquire(blocking=False)]
+lock.release()
+shown = str(lock)
+";
+        let (interpreter, result) = run(code);
+        result.unwrap();
+        let get = |name: &str| interpreter.environment.borrow().get(name).unwrap();
+        assert_eq!(get("after"), booleans(&[true, true, false, false, true, false]));
+        assert_eq!(get("shown"), string("<unlocked lock>"));
+        let held = error("import threading\nlock = threading.Lock()\nlock.acquire()\nlock.acquire()\n");
+        assert!(held.contains("RuntimeError: lock.acquire()"), "{}", held);
+        let released = error("import threading\nthreading.Lock().release()\n");
+        assert!(released.ends_with("Sperre ist nicht gehalten"), "{}", released);
+    }
+
+    #[test]
+    fn with_requires_the_context_manager_protocol() {
+        let message = error("with 5:\n    x = 1\n");
+        let expected = "Kontextmanager-Protokoll nicht (fehlt: __enter__)";
+        assert!(message.ends_with(expected), "{}", message);
+    }
+
+    #[test]
+    fn json_round_trip_and_decode_errors() {
+        let code = "
+import json
+data = {\"a\": [1, 2.5, None, True], \"b\": \"x\"}
+same = json.loads(json.dumps(data)) == data
+text = json.dumps(data, sort_keys=True)
+try:
+    json.loads(\"[1,]\")
+except json.JSONDecodeError as e:
+    caught = isinstance(e, ValueError)
+";
+        assert_eq!(global(code, "same"), Value::Boolean(true));
+        assert_eq!(global(code, "text"), string("{\"a\": [1, 2.5, null, true], \"b\": \"x\"}"));
+        assert_eq!(global(code, "caught"), Value::Boolean(true));
+    }
+
+    #[test]
+    fn module_exceptions_are_not_global_names() {
+        for name in ["JSONDecodeError", "CSVError"] {
+            let message = error(&format!("x = {}\n", name));
+            let expected = format!("NameError: Name '{}' ist nicht definiert", name);
+            assert!(message.ends_with(&expected), "{}", message);
+        }
+        let code = "
+import json
+from json import JSONDecodeError
+same = JSONDecodeError is json.JSONDecodeError
+import csv
+try:
+    csv.parse(\"'a\", quotechar=\"'\")
+except csv.CSVError:
+    caught = True
+";
+        assert_eq!(global(code, "same"), Value::Boolean(true));
+        assert_eq!(global(code, "caught"), Value::Boolean(true));
+    }
+
+    #[test]
+    fn csv_round_trip_through_files_and_objects() {
+        let dir = temp_dir("csv");
+        let code = format!(
+            "
+import csv
+path = \"{}\"
+rows = [[\"name\", \"ort\"], [\"Ada\", \"London, UK\"], [\"Kurt\", None]]
+csv.write(path, rows)
+back = csv.read(path)
+csv.write_dicts(path, [{{\"a\": 1, \"b\": 2}}, {{\"b\": 3}}], fieldnames=[\"a\", \"b\"])
+records = str(csv.read_dicts(path))
+class Sink:
+    def __init__(self):
+        self.text = \"\"
+    def write(self, text):
+        self.text += text
+sink = Sink()
+csv.write(sink, [[1, 2]], delimiter=\";\")
+text = sink.text
+try:
+    csv.write_dicts(path, [{{\"c\": 1}}], fieldnames=[\"a\"])
+except ValueError:
+    rejected = True
+",
+            dir.join("data.csv").display()
+        );
+        let (interpreter, result) = run(&code);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(result, Ok(()));
+        let get = |name: &str| interpreter.environment.borrow().get(name).unwrap();
+        let back = Value::list(vec![
+            Value::list(vec![string("name"), string("ort")]),
+            Value::list(vec![string("Ada"), string("London, UK")]),
+            Value::list(vec![string("Kurt"), string("")]),
+        ]);
+        assert_eq!(get("back"), back);
+        assert_eq!(get("records"), string("[{a: 1, b: 2}, {a: , b: 3}]"));
+        assert_eq!(get("text"), string("1;2\n"));
+        assert_eq!(get("rejected"), Value::Boolean(true));
     }
 }
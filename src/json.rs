@@ -0,0 +1,672 @@
+// src/json.rs
+
+use crate::builtins::reject_kwargs;
+use crate::dict::Dict;
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::{type_name, Interpreter, Value};
+use crate::modules::expect_args;
+use std::rc::Rc;
+
+pub const FUNCTIONS: &[&str] = &["loads", "dumps", "load", "dump"];
+
+// Größte Verschachtelungstiefe beim Lesen und Schreiben; tiefere Strukturen würden den
+// Rust-Stapel sprengen
+const MAX_DEPTH: usize = 500;
+
+pub fn call(
+    interpreter: &mut Interpreter,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    let method = format!("json.{}", name);
+    match name {
+        "loads" => {
+            reject_kwargs(&method, &kwargs)?;
+            expect_args(&method, &args, 1, 1)?;
+            decode(&method, &args[0])
+        }
+        "dumps" => {
+            expect_args(&method, &args, 1, 1)?;
+            let options = Options::from_kwargs(interpreter, &method, kwargs)?;
+            Ok(Value::String(encode(&args[0], &options)?))
+        }
+        // Die Dateivarianten nutzen nur `read()` bzw. `write()` des übergebenen Objekts
+        "load" => {
+            reject_kwargs(&method, &kwargs)?;
+            expect_args(&method, &args, 1, 1)?;
+            let read = interpreter.get_attribute(args[0].clone(), "read")?;
+            let text = interpreter.call_function(&read, Vec::new(), Vec::new())?;
+            decode(&method, &text)
+        }
+        "dump" => {
+            expect_args(&method, &args, 2, 2)?;
+            let options = Options::from_kwargs(interpreter, &method, kwargs)?;
+            let text = encode(&args[0], &options)?;
+            let write = interpreter.get_attribute(args[1].clone(), "write")?;
+            interpreter.call_function(&write, vec![Value::String(text)], Vec::new())?;
+            Ok(Value::Null)
+        }
+        _ => Err(Exception::attribute_error(format!(
+            "Modul 'json' hat kein Attribut '{}'",
+            name
+        ))),
+    }
+}
+
+fn decode(method: &str, text: &Value) -> Result<Value> {
+    let Value::String(text) = text else {
+        return Err(Exception::type_error(format!(
+            "{}() erwartet 'str', nicht '{}'",
+            method,
+            type_name(text)
+        )));
+    };
+    let mut decoder = Decoder {
+        input: text.chars().collect(),
+        position: 0,
+        depth: 0,
+    };
+    decoder.skip_whitespace();
+    let value = decoder.value()?;
+    decoder.skip_whitespace();
+    if decoder.position < decoder.input.len() {
+        return Err(decoder.error("Zusätzliche Daten"));
+    }
+    Ok(value)
+}
+
+// Einstellungen für dumps() und dump()
+struct Options {
+    indent: Option<String>,
+    sort_keys: bool,
+}
+
+impl Options {
+    fn from_kwargs(
+        interpreter: &mut Interpreter,
+        method: &str,
+        kwargs: Vec<(String, Value)>,
+    ) -> Result<Options> {
+        let mut options = Options {
+            indent: None,
+            sort_keys: false,
+        };
+        for (key, value) in kwargs {
+            match (key.as_str(), value) {
+                ("indent", Value::Null) => options.indent = None,
+                ("indent", Value::Number(n)) if n.fract() == 0.0 => {
+                    options.indent = Some(" ".repeat(n.max(0.0) as usize))
+                }
+                ("indent", Value::String(s)) => options.indent = Some(s),
+                ("indent", other) => {
+                    return Err(Exception::type_error(format!(
+                        "{}(): indent muss eine ganze Zahl oder 'str' sein, nicht '{}'",
+                        method,
+                        type_name(&other)
+                    )))
+                }
+                ("sort_keys", value) => options.sort_keys = interpreter.is_truthy(&value)?,
+                (key, _) => {
+                    return Err(Exception::type_error(format!(
+                        "Unbekanntes Schlüsselwortargument '{}' für {}()",
+                        key, method
+                    )))
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+fn encode(value: &Value, options: &Options) -> Result<String> {
+    let mut encoder = Encoder {
+        options,
+        output: String::new(),
+        containers: Vec::new(),
+    };
+    encoder.value(value, 0)?;
+    Ok(encoder.output)
+}
+
+struct Encoder<'a> {
+    options: &'a Options,
+    output: String,
+    containers: Vec<*const ()>, // Listen und Dicts auf dem aktuellen Pfad; für Zyklen
+}
+
+impl Encoder<'_> {
+    fn value(&mut self, value: &Value, level: usize) -> Result<()> {
+        if level >= MAX_DEPTH {
+            return Err(Exception::value_error(format!(
+                "Mehr als {} Ebenen verschachtelt",
+                MAX_DEPTH
+            )));
+        }
+        match value {
+            Value::Null => self.output.push_str("null"),
+            Value::Boolean(b) => self.output.push_str(if *b { "true" } else { "false" }),
+            Value::Number(n) => self.output.push_str(&number(*n)?),
+            Value::String(s) => self.string(s),
+            Value::List(items) => {
+                self.enter(Rc::as_ptr(items) as *const ())?;
+                let items = items.borrow().clone();
+                self.array(&items, level)?;
+                self.containers.pop();
+            }
+            Value::Tuple(items) => self.array(items, level)?,
+            Value::Dict(dict) => {
+                self.enter(Rc::as_ptr(dict) as *const ())?;
+                let mut entries = Vec::new();
+                for (key, value) in dict.borrow().iter() {
+                    entries.push((key_string(key)?, value.clone()));
+                }
+                if self.options.sort_keys {
+                    entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+                }
+                self.object(&entries, level)?;
+                self.containers.pop();
+            }
+            other => {
+                return Err(Exception::type_error(format!(
+                    "Objekt vom Typ '{}' ist nicht JSON-serialisierbar",
+                    match other {
+                        Value::Instance(instance) => instance.class.name.as_str(),
+                        other => type_name(other),
+                    }
+                )))
+            }
+        }
+        Ok(())
+    }
+
+    fn enter(&mut self, container: *const ()) -> Result<()> {
+        if self.containers.contains(&container) {
+            return Err(Exception::value_error("Zirkulärer Verweis erkannt"));
+        }
+        self.containers.push(container);
+        Ok(())
+    }
+
+    fn array(&mut self, items: &[Value], level: usize) -> Result<()> {
+        if items.is_empty() {
+            self.output.push_str("[]");
+            return Ok(());
+        }
+        self.output.push('[');
+        for (i, item) in items.iter().enumerate() {
+            self.separator(i, level + 1);
+            self.value(item, level + 1)?;
+        }
+        self.newline(level);
+        self.output.push(']');
+        Ok(())
+    }
+
+    fn object(&mut self, entries: &[(String, Value)], level: usize) -> Result<()> {
+        if entries.is_empty() {
+            self.output.push_str("{}");
+            return Ok(());
+        }
+        self.output.push('{');
+        for (i, (key, value)) in entries.iter().enumerate() {
+            self.separator(i, level + 1);
+            self.string(key);
+            self.output.push_str(": ");
+            self.value(value, level + 1)?;
+        }
+        self.newline(level);
+        self.output.push('}');
+        Ok(())
+    }
+
+    // Trennzeichen vor einem Element: kompakt ", ", eingerückt ein Zeilenumbruch
+    fn separator(&mut self, index: usize, level: usize) {
+        if index > 0 {
+            self.output.push(',');
+            if self.options.indent.is_none() {
+                self.output.push(' ');
+            }
+        }
+        self.newline(level);
+    }
+
+    fn newline(&mut self, level: usize) {
+        if let Some(indent) = &self.options.indent {
+            self.output.push('\n');
+            self.output.push_str(&indent.repeat(level));
+        }
+    }
+
+    fn string(&mut self, s: &str) {
+        self.output.push('"');
+        for c in s.chars() {
+            match c {
+                '"' => self.output.push_str("\\\""),
+                '\\' => self.output.push_str("\\\\"),
+                '\n' => self.output.push_str("\\n"),
+                '\r' => self.output.push_str("\\r"),
+                '\t' => self.output.push_str("\\t"),
+                '\u{08}' => self.output.push_str("\\b"),
+                '\u{0C}' => self.output.push_str("\\f"),
+                c if (c as u32) < 0x20 => self.output.push_str(&format!("\\u{:04x}", c as u32)),
+                c => self.output.push(c),
+            }
+        }
+        self.output.push('"');
+    }
+}
+
+// Ganze Zahlen ohne Nachkommastellen, sehr große und kleine Beträge mit Exponent
+fn number(n: f64) -> Result<String> {
+    if !n.is_finite() {
+        return Err(Exception::value_error(format!(
+            "Wert {} ist in JSON nicht darstellbar",
+            n
+        )));
+    }
+    let magnitude = n.abs();
+    if magnitude != 0.0 && !(1e-5..1e16).contains(&magnitude) {
+        Ok(format!("{:e}", n))
+    } else {
+        Ok(n.to_string())
+    }
+}
+
+// JSON-Schlüssel sind Zeichenketten; Zahlen, Wahrheitswerte und null werden umgewandelt
+fn key_string(key: &Value) -> Result<String> {
+    match key {
+        Value::String(s) => Ok(s.clone()),
+        Value::Number(n) => number(*n),
+        Value::Boolean(b) => Ok(b.to_string()),
+        Value::Null => Ok("null".to_string()),
+        other => Err(Exception::type_error(format!(
+            "Schlüssel vom Typ '{}' ist in JSON nicht erlaubt",
+            type_name(other)
+        ))),
+    }
+}
+
+struct Decoder {
+    input: Vec<char>,
+    position: usize,
+    depth: usize, // Offene Arrays und Objekte
+}
+
+impl Decoder {
+    fn peek(&self) -> Option<char> {
+        self.input.get(self.position).copied()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.peek(), Some(' ' | '\t' | '\n' | '\r')) {
+            self.position += 1;
+        }
+    }
+
+    // Fehler mit Zeile und Spalte (ab 1) und Zeichenposition (ab 0) wie in Python
+    fn error(&self, message: &str) -> Exception {
+        self.error_at(message, self.position)
+    }
+
+    fn error_at(&self, message: &str, position: usize) -> Exception {
+        let before = &self.input[..position.min(self.input.len())];
+        let line = before.iter().filter(|&&c| c == '\n').count() + 1;
+        let line_start = before.iter().rposition(|&c| c == '\n').map_or(0, |pos| pos + 1);
+        let column = position - line_start + 1;
+        Exception::new(
+            "JSONDecodeError",
+            format!("{}: Zeile {}, Spalte {} (Zeichen {})", message, line, column, position),
+        )
+    }
+
+    fn value(&mut self) -> Result<Value> {
+        match self.peek() {
+            Some('{') => self.nested(Self::object),
+            Some('[') => self.nested(Self::array),
+            Some('"') => Ok(Value::String(self.string()?)),
+            Some('-' | '0'..='9') => self.number(),
+            Some('t') => self.literal("true", Value::Boolean(true)),
+            Some('f') => self.literal("false", Value::Boolean(false)),
+            Some('n') => self.literal("null", Value::Null),
+            _ => Err(self.error("Wert erwartet")),
+        }
+    }
+
+    fn nested(&mut self, parse: fn(&mut Self) -> Result<Value>) -> Result<Value> {
+        if self.depth == MAX_DEPTH {
+            return Err(self.error(&format!("Mehr als {} Ebenen verschachtelt", MAX_DEPTH)));
+        }
+        self.depth += 1;
+        let result = parse(self);
+        self.depth -= 1;
+        result
+    }
+
+    fn literal(&mut self, word: &str, value: Value) -> Result<Value> {
+        let end = self.position + word.chars().count();
+        let found = self.input.get(self.position..end);
+        if found.is_some_and(|found| found.iter().copied().eq(word.chars())) {
+            self.position = end;
+            Ok(value)
+        } else {
+            Err(self.error("Wert erwartet"))
+        }
+    }
+
+    fn object(&mut self) -> Result<Value> {
+        self.position += 1; // '{'
+        let mut dict = Dict::new();
+        self.skip_whitespace();
+        if self.peek() == Some('}') {
+            self.position += 1;
+            return Ok(Value::dict(dict));
+        }
+        loop {
+            self.skip_whitespace();
+            if self.peek() != Some('"') {
+                return Err(self.error("Schlüssel in doppelten Anführungszeichen erwartet"));
+            }
+            let key = self.string()?;
+            self.skip_whitespace();
+            if self.peek() != Some(':') {
+                return Err(self.error("':' erwartet"));
+            }
+            self.position += 1;
+            self.skip_whitespace();
+            let value = self.value()?;
+            dict.insert(Value::String(key), value)?;
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some('}') => {
+                    self.position += 1;
+                    return Ok(Value::dict(dict));
+                }
+                _ => return Err(self.error("',' oder '}' erwartet")),
+            }
+        }
+    }
+
+    fn array(&mut self) -> Result<Value> {
+        self.position += 1; // '['
+        let mut items = Vec::new();
+        self.skip_whitespace();
+        if self.peek() == Some(']') {
+            self.position += 1;
+            return Ok(Value::list(items));
+        }
+        loop {
+            self.skip_whitespace();
+            items.push(self.value()?);
+            self.skip_whitespace();
+            match self.peek() {
+                Some(',') => self.position += 1,
+                Some(']') => {
+                    self.position += 1;
+                    return Ok(Value::list(items));
+                }
+                _ => return Err(self.error("',' oder ']' erwartet")),
+            }
+        }
+    }
+
+    fn string(&mut self) -> Result<String> {
+        let start = self.position;
+        self.position += 1; // '"'
+        let mut result = String::new();
+        loop {
+            let Some(c) = self.peek() else {
+                return Err(self.error_at("Nicht abgeschlossene Zeichenkette", start));
+            };
+            self.position += 1;
+            match c {
+                '"' => return Ok(result),
+                '\\' => {
+                    let escape = self
+                        .peek()
+                        .ok_or_else(|| self.error_at("Nicht abgeschlossene Zeichenkette", start))?;
+                    self.position += 1;
+                    match escape {
+                        '"' => result.push('"'),
+                        '\\' => result.push('\\'),
+                        '/' => result.push('/'),
+                        'b' => result.push('\u{08}'),
+                        'f' => result.push('\u{0C}'),
+                        'n' => result.push('\n'),
+                        'r' => result.push('\r'),
+                        't' => result.push('\t'),
+                        'u' => result.push(self.unicode_escape()?),
+                        _ => {
+                            let position = self.position - 2;
+                            return Err(self.error_at("Ungültige Escape-Sequenz", position));
+                        }
+                    }
+                }
+                c if (c as u32) < 0x20 => {
+                    return Err(self.error_at("Ungültiges Steuerzeichen", self.position - 1))
+                }
+                c => result.push(c),
+            }
+        }
+    }
+
+    // \uXXXX, Ersatzzeichenpaare werden zu einem Zeichen zusammengesetzt
+    fn unicode_escape(&mut self) -> Result<char> {
+        let start = self.position - 2;
+        let high = self.hex4(start)?;
+        if (0xD800..0xDC00).contains(&high)
+            && self.peek() == Some('\\')
+            && self.input.get(self.position + 1) == Some(&'u')
+        {
+            let saved = self.position;
+            self.position += 2;
+            let low = self.hex4(start)?;
+            if (0xDC00..0xE000).contains(&low) {
+                let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                return char::from_u32(code)
+                    .ok_or_else(|| self.error_at("Ungültige \\u-Escape-Sequenz", start));
+            }
+            self.position = saved;
+        }
+        // Einzelne Ersatzzeichen sind in Rust-Zeichenketten nicht darstellbar
+        Ok(char::from_u32(high).unwrap_or(char::REPLACEMENT_CHARACTER))
+    }
+
+    fn hex4(&mut self, start: usize) -> Result<u32> {
+        let digits: String = self.input.iter().skip(self.position).take(4).collect();
+        if digits.len() != 4 || !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(self.error_at("Ungültige \\u-Escape-Sequenz", start));
+        }
+        self.position += 4;
+        Ok(u32::from_str_radix(&digits, 16).unwrap())
+    }
+
+    fn number(&mut self) -> Result<Value> {
+        let start = self.position;
+        if self.peek() == Some('-') {
+            self.position += 1;
+        }
+        // Ganzzahlteil: eine Null oder Ziffern ohne führende Null
+        match self.peek() {
+            Some('0') => self.position += 1,
+            Some('1'..='9') => self.digits(),
+            _ => return Err(self.error_at("Wert erwartet", start)),
+        }
+        if self.peek() == Some('.') {
+            self.position += 1;
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(self.error("Ziffer nach '.' erwartet"));
+            }
+            self.digits();
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            self.position += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.position += 1;
+            }
+            if !matches!(self.peek(), Some('0'..='9')) {
+                return Err(self.error("Ziffer im Exponenten erwartet"));
+            }
+            self.digits();
+        }
+        let text: String = self.input[start..self.position].iter().collect();
+        Ok(Value::Number(text.parse().map_err(|_| self.error_at("Ungültige Zahl", start))?))
+    }
+
+    fn digits(&mut self) {
+        while matches!(self.peek(), Some('0'..='9')) {
+            self.position += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn s(value: &str) -> Value {
+        Value::String(value.to_string())
+    }
+
+    fn loads(text: &str) -> Value {
+        decode("json.loads", &s(text)).expect("JSON sollte gültig sein")
+    }
+
+    fn loads_error(text: &str) -> String {
+        match decode("json.loads", &s(text)) {
+            Err(Exception::Builtin { kind: "JSONDecodeError", message }) => message,
+            other => panic!("JSONDecodeError erwartet, erhalten {:?}", other),
+        }
+    }
+
+    fn dumps(value: &Value) -> String {
+        let options = Options {
+            indent: None,
+            sort_keys: false,
+        };
+        encode(value, &options).expect("Wert sollte kodierbar sein")
+    }
+
+    fn object(entries: Vec<(&str, Value)>) -> Value {
+        let mut dict = Dict::new();
+        for (key, value) in entries {
+            dict.insert(s(key), value).unwrap();
+        }
+        Value::dict(dict)
+    }
+
+    #[test]
+    fn decodes_every_kind_of_value() {
+        let value = loads(r#" {"a": [1, -2.5, 3e2, true, false, null], "b": {}, "c": ""} "#);
+        let expected = object(vec![
+            (
+                "a",
+                Value::list(vec![
+                    Value::Number(1.0),
+                    Value::Number(-2.5),
+                    Value::Number(300.0),
+                    Value::Boolean(true),
+                    Value::Boolean(false),
+                    Value::Null,
+                ]),
+            ),
+            ("b", object(Vec::new())),
+            ("c", s("")),
+        ]);
+        assert_eq!(value, expected);
+    }
+
+    #[test]
+    fn decodes_escapes_and_surrogate_pairs() {
+        assert_eq!(loads(r#""a\"b\\c\/d\n\t\u00e4\ud83d\ude00""#), s("a\"b\\c/d\n\tä😀"));
+        assert_eq!(loads(r#""\ud83d!""#), s("\u{FFFD}!"));
+    }
+
+    #[test]
+    fn decode_errors_carry_their_position() {
+        assert_eq!(loads_error("[1,\n 2,]"), "Wert erwartet: Zeile 2, Spalte 4 (Zeichen 7)");
+        assert_eq!(loads_error("[1] 2"), "Zusätzliche Daten: Zeile 1, Spalte 5 (Zeichen 4)");
+        assert_eq!(loads_error(""), "Wert erwartet: Zeile 1, Spalte 1 (Zeichen 0)");
+        for text in ["[1", "{\"a\" 1}", "tru", "01", "\"\\x\"", "\"\\u12\"", "1."] {
+            loads_error(text);
+        }
+    }
+
+    #[test]
+    fn encodes_compactly_by_default() {
+        let value = object(vec![
+            ("n", Value::list(vec![Value::Number(1.0), Value::Number(0.5), Value::Null])),
+            ("s", s("ä\"\n")),
+            ("t", Value::Tuple(vec![Value::Boolean(true)])),
+        ]);
+        assert_eq!(dumps(&value), r#"{"n": [1, 0.5, null], "s": "ä\"\n", "t": [true]}"#);
+    }
+
+    #[test]
+    fn encodes_with_indent_and_sorted_keys() {
+        let value = object(vec![
+            ("b", Value::list(vec![Value::Number(1.0)])),
+            ("a", object(Vec::new())),
+        ]);
+        let options = Options {
+            indent: Some("  ".to_string()),
+            sort_keys: true,
+        };
+        let expected = "{\n  \"a\": {},\n  \"b\": [\n    1\n  ]\n}";
+        assert_eq!(encode(&value, &options).unwrap(), expected);
+    }
+
+    #[test]
+    fn numbers_use_exponents_only_at_the_extremes() {
+        assert_eq!(number(123.0).unwrap(), "123");
+        assert_eq!(number(1e20).unwrap(), "1e20");
+        assert_eq!(number(-2.5e-7).unwrap(), "-2.5e-7");
+        assert!(number(f64::NAN).is_err());
+        assert!(number(f64::INFINITY).is_err());
+    }
+
+    #[test]
+    fn nesting_depth_is_limited() {
+        let deep = |n: usize| format!("{}{}", "[".repeat(n), "]".repeat(n));
+        let objects = |n: usize| format!("{}1{}", "{\"a\": ".repeat(n), "}".repeat(n));
+        loads(&deep(MAX_DEPTH));
+        loads(&objects(MAX_DEPTH));
+        let expected = format!("Mehr als {} Ebenen verschachtelt", MAX_DEPTH);
+        assert!(loads_error(&deep(200_000)).starts_with(&expected));
+        assert!(loads_error(&objects(MAX_DEPTH + 1)).starts_with(&expected));
+
+        let mut value = Value::Tuple(Vec::new());
+        for _ in 1..MAX_DEPTH {
+            value = Value::Tuple(vec![value]);
+        }
+        assert_eq!(dumps(&value), deep(MAX_DEPTH));
+        let options = Options {
+            indent: None,
+            sort_keys: false,
+        };
+        assert!(matches!(
+            encode(&Value::Tuple(vec![value]), &options),
+            Err(Exception::Builtin { kind: "ValueError", .. })
+        ));
+    }
+
+    #[test]
+    fn cycles_are_value_errors() {
+        let list = Value::list(Vec::new());
+        if let Value::List(items) = &list {
+            items.borrow_mut().push(list.clone());
+        }
+        let options = Options {
+            indent: None,
+            sort_keys: false,
+        };
+        assert!(matches!(
+            encode(&list, &options),
+            Err(Exception::Builtin { kind: "ValueError", .. })
+        ));
+        if let Value::List(items) = &list {
+            items.borrow_mut().clear();
+        }
+    }
+}
@@ -1,27 +1,63 @@
 // src/lexer.rs
 
-use crate::token::Token;
+use crate::token::{Span, Token};
 
 pub struct Lexer {
     input: Vec<char>,
     position: usize,
     current_char: Option<char>,
     indent_stack: Vec<usize>, // Stack zur Verfolgung der Einrückungsebenen
+    nesting: usize, // Tiefe offener Klammern; darin zählen Zeilenumbrüche nicht
+    pending_dedents: usize, // Noch auszugebende Dedents, wenn mehrere Ebenen zugleich enden
+    file: usize,
+    line: usize,
+    column: usize,
+    token_start: Span, // Anfang des zuletzt gelesenen Tokens
 }
 
 impl Lexer {
-    pub fn new(input: String) -> Self {
+    pub fn new(input: String, file: usize) -> Self {
         let chars = input.chars().collect::<Vec<_>>();
-        let first_char = chars.get(0).cloned();
+        let first_char = chars.first().cloned();
         Self {
             input: chars,
             position: 0,
             current_char: first_char,
             indent_stack: vec![0], // Startet mit Einrückungsebene 0
+            nesting: 0,
+            pending_dedents: 0,
+            file,
+            line: 1,
+            column: 1,
+            token_start: Span::default(),
         }
     }
 
+    // Bereich des zuletzt gelesenen Tokens; es endet an der aktuellen Position
+    pub fn span(&self) -> Span {
+        Span {
+            end_line: self.line,
+            end_column: self.column,
+            ..self.token_start
+        }
+    }
+
+    fn mark_token_start(&mut self) {
+        self.token_start = Span {
+            file: self.file,
+            line: self.line,
+            column: self.column,
+            ..Span::default()
+        };
+    }
+
     fn advance(&mut self) {
+        if self.current_char == Some('\n') {
+            self.line += 1;
+            self.column = 1;
+        } else {
+            self.column += 1;
+        }
         self.position += 1;
         if self.position >= self.input.len() {
             self.current_char = None;
@@ -31,14 +67,31 @@ impl Lexer {
     }
 
     fn peek_char(&self) -> Option<char> {
-        if self.position + 1 >= self.input.len() {
-            None
-        } else {
-            Some(self.input[self.position + 1])
-        }
+        self.peek_char_at(1)
+    }
+
+    fn peek_char_at(&self, offset: usize) -> Option<char> {
+        self.input.get(self.position + offset).cloned()
     }
 
    pub fn next_token(&mut self) -> Token {
+        self.mark_token_start();
+        if self.pending_dedents > 0 {
+            self.pending_dedents -= 1;
+            return Token::Dedent;
+        }
+
+        // Innerhalb von Klammern dürfen Ausdrücke über mehrere Zeilen laufen
+        if self.nesting > 0 {
+            while let Some(c) = self.current_char {
+                if c.is_whitespace() {
+                    self.advance();
+                } else {
+                    break;
+                }
+            }
+        }
+
         // Behandle Einrückungen und Ausrückungen nach Newline
         if self.current_char == Some('\n') {
             self.advance(); // Überspringe '\n'
@@ -74,7 +127,15 @@ impl Lexer {
                 println!("Lexer: Indent"); // Debug-Ausgabe
                 return Token::Indent;
             } else if num_spaces < current_indent {
-                self.indent_stack.pop();
+                // Für jede geschlossene Ebene ein Dedent
+                while *self.indent_stack.last().unwrap() > num_spaces {
+                    self.indent_stack.pop();
+                    self.pending_dedents += 1;
+                }
+                if *self.indent_stack.last().unwrap() != num_spaces {
+                    panic!("Einrückung passt zu keiner äußeren Ebene");
+                }
+                self.pending_dedents -= 1;
                 println!("Lexer: Dedent"); // Debug-Ausgabe
                 return Token::Dedent;
             } else {
@@ -103,6 +164,7 @@ impl Lexer {
             return Token::EOF;
         }
 
+        self.mark_token_start();
         let c = self.current_char.unwrap();
 
         // Behandle Kommentare
@@ -128,7 +190,7 @@ impl Lexer {
         }
 
         // Behandle Zahlen
-        if c.is_digit(10) {
+        if c.is_ascii_digit() {
             return self.number();
         }
 
@@ -169,7 +231,12 @@ impl Lexer {
         }
 
         if c == '<' || c == '>' {
-            if let Some('=') = self.peek_char() {
+            if self.peek_char() == Some(c) {
+                // Bitweise Verschiebung: << oder >>
+                self.advance();
+                self.advance();
+                return Token::Operator(format!("{}{}", c, c));
+            } else if let Some('=') = self.peek_char() {
                 let op = format!("{}=", c);
                 self.advance();
                 self.advance();
@@ -184,9 +251,29 @@ impl Lexer {
             }
         }
 
+        // Behandle erweiterte Zuweisungen (+=, -=, *=, /=, %=, **=, //=)
+        if matches!(c, '+' | '-' | '*' | '/' | '%') {
+            let doubled = (c == '*' || c == '/') && self.peek_char() == Some(c);
+            let op_len = if doubled { 2 } else { 1 };
+            if self.peek_char_at(op_len) == Some('=') {
+                let op: String = std::iter::repeat_n(c, op_len).collect();
+                for _ in 0..=op_len {
+                    self.advance();
+                }
+                return Token::AugAssign(op);
+            }
+        }
+
+        // Behandle Potenz (**) und Ganzzahldivision (//)
+        if (c == '*' || c == '/') && self.peek_char() == Some(c) {
+            self.advance();
+            self.advance();
+            return Token::Operator(format!("{}{}", c, c));
+        }
+
         // Behandle einfache Operatoren und Satzzeichen
         match c {
-            '+' | '-' | '*' | '/' | '%' => {
+            '+' | '-' | '*' | '/' | '%' | '&' | '|' | '^' | '~' => {
                 self.advance();
                 let token = Token::Operator(c.to_string());
                 println!("Lexer: {:?}", token); // Debug-Ausgabe
@@ -194,28 +281,46 @@ impl Lexer {
             }
             '(' => {
                 self.advance();
+                self.nesting += 1;
                 let token = Token::LeftParen;
                 println!("Lexer: {:?}", token); // Debug-Ausgabe
                 token
             }
             ')' => {
                 self.advance();
+                self.nesting = self.nesting.saturating_sub(1);
                 let token = Token::RightParen;
                 println!("Lexer: {:?}", token); // Debug-Ausgabe
                 token
             }
             '[' => { // Behandle LeftBracket
                 self.advance();
+                self.nesting += 1;
                 let token = Token::LeftBracket;
                 println!("Lexer: {:?}", token); // Debug-Ausgabe
                 token
             }
             ']' => { // Behandle RightBracket
                 self.advance();
+                self.nesting = self.nesting.saturating_sub(1);
                 let token = Token::RightBracket;
                 println!("Lexer: {:?}", token); // Debug-Ausgabe
                 token
             }
+            '{' => {
+                self.advance();
+                self.nesting += 1;
+                Token::LeftBrace
+            }
+            '}' => {
+                self.advance();
+                self.nesting = self.nesting.saturating_sub(1);
+                Token::RightBrace
+            }
+            '.' => {
+                self.advance();
+                Token::Dot
+            }
             ',' => {
                 self.advance();
                 let token = Token::Comma;
@@ -249,14 +354,29 @@ impl Lexer {
             "if" => Token::If,
             "else" => Token::Else,
             "while" => Token::While,
+            "for" => Token::For,
             "def" => Token::Def,
+            "class" => Token::Class,
             "return" => Token::Return,
+            "try" => Token::Try,
+            "except" => Token::Except,
+            "finally" => Token::Finally,
+            "raise" => Token::Raise,
+            "assert" => Token::Assert,
+            "import" => Token::Import,
+            "from" => Token::From,
+            "with" => Token::With,
+            "as" => Token::As,
+            "lambda" => Token::Lambda,
             "print" => Token::Print,
             "True" => Token::True,
             "False" => Token::False,
+            "None" => Token::None,
             "and" => Token::And,
             "or" => Token::Or,
             "not" => Token::Not,
+            "in" => Token::In,
+            "is" => Token::Is,
             _ => Token::Identifier(result),
         }
     }
@@ -265,7 +385,7 @@ impl Lexer {
         let mut result = String::new();
         let mut has_decimal_point = false;
         while let Some(c) = self.current_char {
-            if c.is_digit(10) {
+            if c.is_ascii_digit() {
                 result.push(c);
                 self.advance();
             } else if c == '.' && !has_decimal_point {
@@ -5,8 +5,21 @@ mod lexer;
 mod ast;
 mod parser;
 mod interpreter;
+mod builtins;
+mod operators;
+mod dict;
+mod methods;
+mod string_methods;
+mod exceptions;
+mod modules;
+mod math;
+mod random;
+mod time;
+mod files;
+mod json;
+mod csv;
+mod threading;
 
-use lexer::Lexer;
 use parser::Parser;
 use interpreter::Interpreter;
 use std::io::{self, Write};
@@ -14,36 +27,41 @@ use std::env;
 use std::fs;
 
 fn main() {
-    let args: Vec<String> = env::args().collect();
+    let mut args: Vec<String> = env::args().skip(1).collect();
     let mut interpreter = Interpreter::new();
 
-    if args.len() > 1 {
+    // `-O` schaltet wie in Python alle assert-Anweisungen ab
+    if let Some(pos) = args.iter().position(|arg| arg == "-O") {
+        args.remove(pos);
+        interpreter.disable_assertions();
+    }
+
+    if let Some(file_path) = args.first() {
         // Dateipfad wurde übergeben
-        let file_path = &args[1];
         let code = fs::read_to_string(file_path)
             .unwrap_or_else(|_| panic!("Konnte Datei '{}' nicht lesen", file_path));
-        execute_code(&code, &mut interpreter);
+        if !execute_code(&code, file_path, &mut interpreter) {
+            std::process::exit(1);
+        }
     } else {
         // Interaktiver Modus
         repl(&mut interpreter);
     }
 }
 
-fn execute_code(code: &str, interpreter: &mut Interpreter) {
-    let mut lexer = Lexer::new(code.to_string());
-    let mut tokens = Vec::new();
-    loop {
-        let tok = lexer.next_token();
-        if tok == token::Token::EOF {
-            break;
-        }
-        tokens.push(tok);
-    }
-
-    let mut parser = Parser::new(tokens);
+// Gibt false zurück, wenn eine Ausnahme nicht abgefangen wurde
+fn execute_code(code: &str, path: &str, interpreter: &mut Interpreter) -> bool {
+    let file = interpreter.add_source(path, code);
+    let mut parser = Parser::from_source(code, file);
     let ast = parser.parse();
 
-    interpreter.interpret(&ast);
+    match interpreter.interpret(&ast) {
+        Ok(_) => true,
+        Err(exception) => {
+            eprintln!("{}", interpreter.describe_exception(exception));
+            false
+        }
+    }
 }
 
 fn repl(interpreter: &mut Interpreter) {
@@ -64,6 +82,6 @@ fn repl(interpreter: &mut Interpreter) {
             continue;
         }
 
-        execute_code(&input, interpreter);
+        execute_code(&input, "<stdin>", interpreter);
     }
 }
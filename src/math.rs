@@ -0,0 +1,181 @@
+// src/math.rs
+
+use crate::builtins::reject_kwargs;
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::Value;
+use crate::modules::{expect_args, integer, number};
+use std::f64::consts;
+
+pub const FUNCTIONS: &[&str] = &[
+    "sqrt", "floor", "ceil", "trunc", "fabs", "exp", "log", "log2", "log10", "pow", "sin", "cos",
+    "tan", "asin", "acos", "atan", "atan2", "sinh", "cosh", "tanh", "hypot", "degrees", "radians",
+    "isclose", "isnan", "isinf", "isfinite", "factorial", "gcd",
+];
+
+pub fn constants() -> Vec<(&'static str, Value)> {
+    vec![
+        ("pi", Value::Number(consts::PI)),
+        ("e", Value::Number(consts::E)),
+        ("tau", Value::Number(consts::TAU)),
+        ("inf", Value::Number(f64::INFINITY)),
+        ("nan", Value::Number(f64::NAN)),
+    ]
+}
+
+pub fn call(name: &str, args: Vec<Value>, kwargs: Vec<(String, Value)>) -> Result<Value> {
+    let method = format!("math.{}", name);
+    if name == "isclose" {
+        return isclose(&method, &args, kwargs);
+    }
+    reject_kwargs(&method, &kwargs)?;
+    let result = match name {
+        "log" => {
+            expect_args(&method, &args, 1, 2)?;
+            let x = checked_log(number(&method, &args[0])?)?;
+            match args.get(1) {
+                Some(base) => {
+                    let base = checked_log(number(&method, base)?)?;
+                    if base == 0.0 {
+                        return Err(Exception::zero_division());
+                    }
+                    x / base
+                }
+                None => x,
+            }
+        }
+        "pow" | "atan2" | "hypot" => {
+            expect_args(&method, &args, 2, 2)?;
+            let (x, y) = (number(&method, &args[0])?, number(&method, &args[1])?);
+            let value = match name {
+                "pow" => x.powf(y),
+                "atan2" => x.atan2(y),
+                _ => x.hypot(y),
+            };
+            if value.is_nan() && !x.is_nan() && !y.is_nan() {
+                return Err(domain_error());
+            }
+            value
+        }
+        "factorial" => {
+            expect_args(&method, &args, 1, 1)?;
+            let n = integer(&method, &args[0])?;
+            if n < 0 {
+                return Err(Exception::value_error(
+                    "factorial() ist für negative Zahlen nicht definiert",
+                ));
+            }
+            // 171! liegt bereits jenseits des größten f64
+            if n > 170 {
+                return Err(Exception::new(
+                    "OverflowError",
+                    format!("factorial({}) ist als Zahl nicht darstellbar", n),
+                ));
+            }
+            (1..=n).fold(1.0, |acc, k| acc * k as f64)
+        }
+        "gcd" => {
+            // Ohne Vorzeichen gerechnet, damit auch |-2^63| darstellbar ist
+            let mut result: u64 = 0;
+            for arg in &args {
+                let mut a = result;
+                let mut b = integer(&method, arg)?.unsigned_abs();
+                while b != 0 {
+                    (a, b) = (b, a % b);
+                }
+                result = a;
+            }
+            result as f64
+        }
+        "isnan" | "isinf" | "isfinite" => {
+            expect_args(&method, &args, 1, 1)?;
+            let x = number(&method, &args[0])?;
+            let result = match name {
+                "isnan" => x.is_nan(),
+                "isinf" => x.is_infinite(),
+                _ => x.is_finite(),
+            };
+            return Ok(Value::Boolean(result));
+        }
+        _ => {
+            expect_args(&method, &args, 1, 1)?;
+            let x = number(&method, &args[0])?;
+            let value = match name {
+                "sqrt" => x.sqrt(),
+                "floor" => x.floor(),
+                "ceil" => x.ceil(),
+                "trunc" => x.trunc(),
+                "fabs" => x.abs(),
+                "exp" => x.exp(),
+                "log2" => checked_log(x)? / consts::LN_2,
+                "log10" => checked_log(x)? / consts::LN_10,
+                "sin" => x.sin(),
+                "cos" => x.cos(),
+                "tan" => x.tan(),
+                "asin" => x.asin(),
+                "acos" => x.acos(),
+                "atan" => x.atan(),
+                "sinh" => x.sinh(),
+                "cosh" => x.cosh(),
+                "tanh" => x.tanh(),
+                "degrees" => x.to_degrees(),
+                "radians" => x.to_radians(),
+                _ => {
+                    return Err(Exception::attribute_error(format!(
+                        "Modul 'math' hat kein Attribut '{}'",
+                        name
+                    )))
+                }
+            };
+            // Wie in Python: NaN aus einem gültigen Argument heißt, es lag außerhalb des Definitionsbereichs
+            if value.is_nan() && !x.is_nan() {
+                return Err(domain_error());
+            }
+            value
+        }
+    };
+    Ok(Value::Number(result))
+}
+
+// Vergleich mit relativer und absoluter Toleranz wie math.isclose in Python
+fn isclose(method: &str, args: &[Value], kwargs: Vec<(String, Value)>) -> Result<Value> {
+    expect_args(method, args, 2, 2)?;
+    let (a, b) = (number(method, &args[0])?, number(method, &args[1])?);
+    let mut rel_tol = 1e-9;
+    let mut abs_tol = 0.0;
+    for (key, value) in kwargs {
+        match key.as_str() {
+            "rel_tol" => rel_tol = number(method, &value)?,
+            "abs_tol" => abs_tol = number(method, &value)?,
+            _ => {
+                return Err(Exception::type_error(format!(
+                    "Unbekanntes Schlüsselwortargument '{}' für {}()",
+                    key, method
+                )))
+            }
+        }
+    }
+    if rel_tol < 0.0 || abs_tol < 0.0 {
+        return Err(Exception::value_error("Toleranzen dürfen nicht negativ sein"));
+    }
+    if a == b {
+        return Ok(Value::Boolean(true));
+    }
+    if a.is_infinite() || b.is_infinite() {
+        return Ok(Value::Boolean(false));
+    }
+    let diff = (a - b).abs();
+    Ok(Value::Boolean(
+        diff <= (rel_tol * b.abs()).max(rel_tol * a.abs()) || diff <= abs_tol,
+    ))
+}
+
+fn checked_log(x: f64) -> Result<f64> {
+    if x <= 0.0 {
+        return Err(domain_error());
+    }
+    Ok(x.ln())
+}
+
+fn domain_error() -> Exception {
+    Exception::value_error("Argument außerhalb des mathematischen Definitionsbereichs")
+}
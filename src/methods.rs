@@ -0,0 +1,304 @@
+// src/methods.rs
+
+use crate::builtins::{reject_kwargs, sort_values};
+use crate::dict::Dict;
+use crate::exceptions::{Exception, Result};
+use crate::files::{call_file_method, FILE_METHODS};
+use crate::interpreter::{type_name, Interpreter, Value};
+use crate::modules::{expect_args, integer};
+use crate::string_methods::{call_string_method, STRING_METHODS};
+use crate::threading::{call_lock_method, LOCK_METHODS};
+use std::cell::RefCell;
+
+const TUPLE_METHODS: &[&str] = &["index", "count"];
+
+const LIST_METHODS: &[&str] = &[
+    "append", "extend", "insert", "pop", "remove", "clear", "index", "count", "reverse", "sort",
+    "copy",
+];
+
+const DICT_METHODS: &[&str] = &[
+    "keys", "values", "items", "get", "pop", "setdefault", "update", "clear", "copy",
+];
+
+// Eingebaute Methoden je Werttyp
+fn methods_for(receiver: &Value) -> &'static [&'static str] {
+    match receiver {
+        Value::String(_) => STRING_METHODS,
+        Value::List(_) => LIST_METHODS,
+        Value::Tuple(_) => TUPLE_METHODS,
+        Value::Dict(_) => DICT_METHODS,
+        Value::File(_) => FILE_METHODS,
+        Value::Lock(_) => LOCK_METHODS,
+        _ => &[],
+    }
+}
+
+pub fn has_method(receiver: &Value, name: &str) -> bool {
+    methods_for(receiver).contains(&name)
+}
+
+pub fn call_method(
+    interpreter: &mut Interpreter,
+    receiver: &Value,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    match receiver {
+        Value::String(s) => call_string_method(interpreter, s, name, args, kwargs),
+        Value::List(items) => call_list_method(interpreter, items, name, args, kwargs),
+        Value::Tuple(items) => {
+            reject_kwargs(name, &kwargs)?;
+            call_sequence_method(interpreter, "tuple", items, name, args)
+        }
+        Value::Dict(dict) => {
+            reject_kwargs(name, &kwargs)?;
+            call_dict_method(interpreter, dict, name, args)
+        }
+        Value::File(file) => call_file_method(file, name, args, kwargs),
+        Value::Lock(lock) => call_lock_method(lock, name, args, kwargs),
+        _ => Err(no_method(type_name(receiver), name)),
+    }
+}
+
+// Methoden, die Listen und Tupel gemeinsam haben
+fn call_sequence_method(
+    interpreter: &mut Interpreter,
+    type_name: &str,
+    items: &[Value],
+    name: &str,
+    args: Vec<Value>,
+) -> Result<Value> {
+    match name {
+        "index" => {
+            expect_args(&format!("{}.index", type_name), &args, 1, 1)?;
+            for (pos, item) in items.iter().enumerate() {
+                if interpreter.values_equal(item, &args[0])? {
+                    return Ok(Value::Number(pos as f64));
+                }
+            }
+            Err(Exception::value_error(format!(
+                "{} ist nicht in {}",
+                interpreter.value_to_string(&args[0])?,
+                type_name
+            )))
+        }
+        "count" => {
+            expect_args(&format!("{}.count", type_name), &args, 1, 1)?;
+            let mut count = 0;
+            for item in items {
+                if interpreter.values_equal(item, &args[0])? {
+                    count += 1;
+                }
+            }
+            Ok(Value::Number(count as f64))
+        }
+        _ => Err(no_method(type_name, name)),
+    }
+}
+
+fn call_list_method(
+    interpreter: &mut Interpreter,
+    items: &RefCell<Vec<Value>>,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    if name == "sort" {
+        expect_args("list.sort", &args, 0, 0)?;
+        // Die Liste bleibt während der Schlüsselaufrufe lesbar
+        let current = items.borrow().clone();
+        let sorted = sort_values(interpreter, current, kwargs, "list.sort")?;
+        *items.borrow_mut() = sorted;
+        return Ok(Value::Null);
+    }
+    reject_kwargs(name, &kwargs)?;
+    match name {
+        "append" => {
+            expect_args("list.append", &args, 1, 1)?;
+            items.borrow_mut().push(args[0].clone());
+            Ok(Value::Null)
+        }
+        "extend" => {
+            expect_args("list.extend", &args, 1, 1)?;
+            let extra = interpreter.iterate(&args[0])?;
+            items.borrow_mut().extend(extra);
+            Ok(Value::Null)
+        }
+        "insert" => {
+            expect_args("list.insert", &args, 2, 2)?;
+            let index = integer("list.insert", &args[0])?;
+            let mut items = items.borrow_mut();
+            let len = items.len() as i64;
+            // Wie in Python wird ein Index außerhalb des Bereichs begrenzt
+            let pos = if index < 0 { (index + len).max(0) } else { index.min(len) };
+            items.insert(pos as usize, args[1].clone());
+            Ok(Value::Null)
+        }
+        "pop" => {
+            expect_args("list.pop", &args, 0, 1)?;
+            let mut items = items.borrow_mut();
+            if items.is_empty() {
+                return Err(Exception::index_error("pop aus leerer Liste"));
+            }
+            let len = items.len() as i64;
+            let index = match args.first() {
+                Some(i) => integer("list.pop", i)?,
+                None => len - 1,
+            };
+            let pos = if index < 0 { index + len } else { index };
+            if pos < 0 || pos >= len {
+                return Err(Exception::index_error(format!(
+                    "Index für pop außerhalb des gültigen Bereichs: {}",
+                    index
+                )));
+            }
+            Ok(items.remove(pos as usize))
+        }
+        "remove" => {
+            expect_args("list.remove", &args, 1, 1)?;
+            let snapshot = items.borrow().clone();
+            for (pos, item) in snapshot.iter().enumerate() {
+                if interpreter.values_equal(item, &args[0])? {
+                    // `__eq__` kann die Liste verändert haben; das gefundene Element wird daher
+                    // in der aktuellen Liste erneut gesucht
+                    let mut live = items.borrow_mut();
+                    let same = |current: &Value| interpreter.is_identical(current, item);
+                    let found = match live.get(pos) {
+                        Some(current) if same(current) => Some(pos),
+                        _ => live.iter().position(same),
+                    };
+                    let Some(found) = found else {
+                        return Err(Exception::new(
+                            "RuntimeError",
+                            "Liste wurde während list.remove() verändert",
+                        ));
+                    };
+                    live.remove(found);
+                    return Ok(Value::Null);
+                }
+            }
+            Err(Exception::value_error(format!(
+                "list.remove(x): {} ist nicht in der Liste",
+                interpreter.value_to_string(&args[0])?
+            )))
+        }
+        "clear" => {
+            expect_args("list.clear", &args, 0, 0)?;
+            items.borrow_mut().clear();
+            Ok(Value::Null)
+        }
+        "index" | "count" => {
+            let items = items.borrow().clone();
+            call_sequence_method(interpreter, "list", &items, name, args)
+        }
+        "reverse" => {
+            expect_args("list.reverse", &args, 0, 0)?;
+            items.borrow_mut().reverse();
+            Ok(Value::Null)
+        }
+        "copy" => {
+            expect_args("list.copy", &args, 0, 0)?;
+            Ok(Value::list(items.borrow().clone()))
+        }
+        _ => Err(no_method("list", name)),
+    }
+}
+
+fn call_dict_method(
+    interpreter: &mut Interpreter,
+    dict: &RefCell<Dict>,
+    name: &str,
+    args: Vec<Value>,
+) -> Result<Value> {
+    match name {
+        "keys" => {
+            expect_args("dict.keys", &args, 0, 0)?;
+            Ok(Value::list(dict.borrow().keys().cloned().collect()))
+        }
+        "values" => {
+            expect_args("dict.values", &args, 0, 0)?;
+            Ok(Value::list(dict.borrow().iter().map(|(_, v)| v.clone()).collect()))
+        }
+        "items" => {
+            expect_args("dict.items", &args, 0, 0)?;
+            let items = dict
+                .borrow()
+                .iter()
+                .map(|(k, v)| Value::Tuple(vec![k.clone(), v.clone()]))
+                .collect();
+            Ok(Value::list(items))
+        }
+        "get" => {
+            expect_args("dict.get", &args, 1, 2)?;
+            let default = args.get(1).cloned().unwrap_or(Value::Null);
+            Ok(dict.borrow().get(&args[0])?.cloned().unwrap_or(default))
+        }
+        "pop" => {
+            expect_args("dict.pop", &args, 1, 2)?;
+            let removed = dict.borrow_mut().remove(&args[0])?;
+            match (removed, args.get(1)) {
+                (Some(value), _) => Ok(value),
+                (None, Some(default)) => Ok(default.clone()),
+                (None, None) => Err(Exception::key_error(format!(
+                    "Schlüssel nicht gefunden: {}",
+                    interpreter.value_to_string(&args[0])?
+                ))),
+            }
+        }
+        "setdefault" => {
+            expect_args("dict.setdefault", &args, 1, 2)?;
+            let mut dict = dict.borrow_mut();
+            if let Some(value) = dict.get(&args[0])? {
+                return Ok(value.clone());
+            }
+            let default = args.get(1).cloned().unwrap_or(Value::Null);
+            dict.insert(args[0].clone(), default.clone())?;
+            Ok(default)
+        }
+        "update" => {
+            expect_args("dict.update", &args, 1, 1)?;
+            let entries: Vec<(Value, Value)> = match &args[0] {
+                Value::Dict(other) => other
+                    .borrow()
+                    .iter()
+                    .map(|(k, v)| (k.clone(), v.clone()))
+                    .collect(),
+                other => {
+                    let mut entries = Vec::new();
+                    for pair in interpreter.iterate(other)? {
+                        match interpreter.iterate(&pair)?.as_slice() {
+                            [k, v] => entries.push((k.clone(), v.clone())),
+                            _ => {
+                                return Err(Exception::value_error(
+                                    "dict.update erwartet Paare aus Schlüssel und Wert",
+                                ))
+                            }
+                        }
+                    }
+                    entries
+                }
+            };
+            let mut dict = dict.borrow_mut();
+            for (key, value) in entries {
+                dict.insert(key, value)?;
+            }
+            Ok(Value::Null)
+        }
+        "clear" => {
+            expect_args("dict.clear", &args, 0, 0)?;
+            dict.borrow_mut().clear();
+            Ok(Value::Null)
+        }
+        "copy" => {
+            expect_args("dict.copy", &args, 0, 0)?;
+            Ok(Value::dict(dict.borrow().clone()))
+        }
+        _ => Err(no_method("dict", name)),
+    }
+}
+
+fn no_method(type_name: &str, name: &str) -> Exception {
+    Exception::attribute_error(format!("'{}' hat keine Methode '{}'", type_name, name))
+}
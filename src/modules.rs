@@ -0,0 +1,119 @@
+// src/modules.rs
+
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::{type_name, Interpreter, Value};
+use crate::{csv, files, json, math, random, threading, time};
+use std::env;
+use std::path::{Path, PathBuf};
+
+// Sucht `name.lum` erst neben der importierenden Datei, dann in den Verzeichnissen aus
+// LUMINA_PATH (getrennt wie PATH)
+pub fn resolve(name: &str, importer: &Path) -> Option<PathBuf> {
+    let mut dirs = vec![importer.parent().map(Path::to_path_buf).unwrap_or_default()];
+    if let Some(paths) = env::var_os("LUMINA_PATH") {
+        dirs.extend(env::split_paths(&paths));
+    }
+    dirs.into_iter()
+        .map(|dir| dir.join(format!("{}.lum", name)))
+        .find(|path| path.is_file())
+}
+
+// Globale Namen eines eingebauten Moduls; seine Funktionen sind in Rust geschrieben und
+// heißen intern "modul.funktion"
+pub fn native_globals(name: &str) -> Option<Vec<(String, Value)>> {
+    let (functions, constants) = match name {
+        "math" => (math::FUNCTIONS, math::constants()),
+        "random" => (random::FUNCTIONS, Vec::new()),
+        "time" => (time::FUNCTIONS, Vec::new()),
+        "fs" => (files::FUNCTIONS, Vec::new()),
+        "json" => (json::FUNCTIONS, Vec::new()),
+        "csv" => (csv::FUNCTIONS, Vec::new()),
+        "threading" => (threading::FUNCTIONS, Vec::new()),
+        _ => return None,
+    };
+    let functions = functions
+        .iter()
+        .map(|function| (function.to_string(), Value::Builtin(format!("{}.{}", name, function))));
+    let constants = constants.into_iter().map(|(name, value)| (name.to_string(), value));
+    Some(functions.chain(constants).collect())
+}
+
+// Ausnahmeklassen, die ein eingebautes Modul zusätzlich als Attribut anbietet
+pub fn native_exceptions(name: &str) -> &'static [&'static str] {
+    match name {
+        "json" => &["JSONDecodeError"],
+        "csv" => &["CSVError"],
+        _ => &[],
+    }
+}
+
+pub fn call_native(
+    interpreter: &mut Interpreter,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    match name.split_once('.') {
+        Some(("math", function)) => math::call(function, args, kwargs),
+        Some(("random", function)) => random::call(interpreter, function, args, kwargs),
+        Some(("time", function)) => time::call(function, args, kwargs),
+        Some(("fs", function)) => files::call(function, args, kwargs),
+        Some(("json", function)) => json::call(interpreter, function, args, kwargs),
+        Some(("csv", function)) => csv::call(interpreter, function, args, kwargs),
+        Some(("threading", function)) => threading::call(function, args, kwargs),
+        _ => Err(Exception::new("NameError", format!("Unbekannte eingebaute Funktion: {}", name))),
+    }
+}
+
+// Gemeinsame Argumentprüfungen der eingebauten Module
+
+pub fn expect_args(name: &str, args: &[Value], min: usize, max: usize) -> Result<()> {
+    if args.len() < min || args.len() > max {
+        let expected = if min == max {
+            min.to_string()
+        } else {
+            format!("{} bis {}", min, max)
+        };
+        return Err(Exception::type_error(format!(
+            "{}() erwartet {} Argument(e), erhalten {}",
+            name,
+            expected,
+            args.len()
+        )));
+    }
+    Ok(())
+}
+
+pub fn number(name: &str, value: &Value) -> Result<f64> {
+    match value {
+        Value::Number(n) => Ok(*n),
+        Value::Boolean(b) => Ok(f64::from(u8::from(*b))),
+        other => Err(Exception::type_error(format!(
+            "{}() erwartet eine Zahl, nicht '{}'",
+            name,
+            type_name(other)
+        ))),
+    }
+}
+
+pub fn integer(name: &str, value: &Value) -> Result<i64> {
+    whole_number(number(name, value)?)?.ok_or_else(|| {
+        Exception::type_error(format!("{}() erwartet eine ganze Zahl", name))
+    })
+}
+
+// Der Wert als i64, oder None bei Nachkommastellen. Ganze Zahlen außerhalb von i64 werden
+// abgelehnt statt stillschweigend abgeschnitten.
+pub fn whole_number(n: f64) -> Result<Option<i64>> {
+    if n.fract() != 0.0 || !n.is_finite() {
+        return Ok(None);
+    }
+    // -2^63 ist als f64 exakt darstellbar, 2^63 liegt bereits außerhalb
+    if n < i64::MIN as f64 || n >= -(i64::MIN as f64) {
+        return Err(Exception::new(
+            "OverflowError",
+            format!("{} ist zu groß für eine ganze Zahl", n),
+        ));
+    }
+    Ok(Some(n as i64))
+}
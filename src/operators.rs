@@ -0,0 +1,383 @@
+// src/operators.rs
+
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::{type_name, Value};
+use crate::modules::whole_number;
+
+type BinaryHandler = fn(&Value, &Value) -> Result<Value>;
+
+// Eine Regel gilt für einen Operator und ein Paar von Operandentypen (siehe `type_name`)
+struct BinaryRule {
+    operator: &'static str,
+    left: &'static str,
+    right: &'static str,
+    apply: BinaryHandler,
+}
+
+const fn rule(
+    operator: &'static str,
+    left: &'static str,
+    right: &'static str,
+    apply: BinaryHandler,
+) -> BinaryRule {
+    BinaryRule {
+        operator,
+        left,
+        right,
+        apply,
+    }
+}
+
+// Alle arithmetischen und bitweisen Operatoren. Kombinationen, die hier fehlen, sind ein
+// Typfehler; Vergleiche, Identität und Logik behandelt der Interpreter selbst.
+static BINARY_RULES: &[BinaryRule] = &[
+    rule("+", "number", "number", |l, r| Ok(Value::Number(num(l) + num(r)))),
+    rule("+", "str", "str", |l, r| Ok(Value::String(format!("{}{}", string(l), string(r))))),
+    rule("+", "list", "list", |l, r| Ok(Value::list([items(l), items(r)].concat()))),
+    rule("+", "tuple", "tuple", |l, r| Ok(Value::Tuple([items(l), items(r)].concat()))),
+    rule("-", "number", "number", |l, r| Ok(Value::Number(num(l) - num(r)))),
+    rule("*", "number", "number", |l, r| Ok(Value::Number(num(l) * num(r)))),
+    rule("*", "str", "number", |l, r| Ok(Value::String(repeat_string(l, r)?))),
+    rule("*", "number", "str", |l, r| Ok(Value::String(repeat_string(r, l)?))),
+    rule("*", "list", "number", |l, r| Ok(Value::list(repeat(l, r)?))),
+    rule("*", "number", "list", |l, r| Ok(Value::list(repeat(r, l)?))),
+    rule("*", "tuple", "number", |l, r| Ok(Value::Tuple(repeat(l, r)?))),
+    rule("*", "number", "tuple", |l, r| Ok(Value::Tuple(repeat(r, l)?))),
+    rule("/", "number", "number", |l, r| Ok(Value::Number(num(l) / divisor(r)?))),
+    rule("//", "number", "number", |l, r| Ok(Value::Number((num(l) / divisor(r)?).floor()))),
+    // Vorzeichen folgt dem Divisor, passend zu '//'
+    rule("%", "number", "number", |l, r| {
+        let (l, r) = (num(l), divisor(r)?);
+        Ok(Value::Number(l - r * (l / r).floor()))
+    }),
+    rule("**", "number", "number", |l, r| {
+        if num(l) == 0.0 && num(r) < 0.0 {
+            return Err(Exception::zero_division());
+        }
+        Ok(Value::Number(num(l).powf(num(r))))
+    }),
+    rule("&", "number", "number", |l, r| Ok(Value::Number((int(l, "&")? & int(r, "&")?) as f64))),
+    rule("|", "number", "number", |l, r| Ok(Value::Number((int(l, "|")? | int(r, "|")?) as f64))),
+    rule("^", "number", "number", |l, r| Ok(Value::Number((int(l, "^")? ^ int(r, "^")?) as f64))),
+    rule("<<", "number", "number", |l, r| {
+        let (l, r) = (int(l, "<<")?, shift(r, "<<")?);
+        let result = l
+            .checked_shl(r as u32)
+            .filter(|v| v >> r == l)
+            .ok_or_else(|| Exception::new("OverflowError", format!("Überlauf bei '{} << {}'", l, r)))?;
+        Ok(Value::Number(result as f64))
+    }),
+    rule(">>", "number", "number", |l, r| {
+        Ok(Value::Number((int(l, ">>")? >> shift(r, ">>")?.min(63)) as f64))
+    }),
+];
+
+// Spezialmethoden für Operatoren auf Objekten: die des linken Operanden und die
+// gespiegelte des rechten, die versucht wird, wenn links keine Methode existiert
+static SPECIAL_METHODS: &[(&str, &str, &str)] = &[
+    ("+", "__add__", "__radd__"),
+    ("-", "__sub__", "__rsub__"),
+    ("*", "__mul__", "__rmul__"),
+    ("/", "__truediv__", "__rtruediv__"),
+    ("//", "__floordiv__", "__rfloordiv__"),
+    ("%", "__mod__", "__rmod__"),
+    ("**", "__pow__", "__rpow__"),
+    ("&", "__and__", "__rand__"),
+    ("|", "__or__", "__ror__"),
+    ("^", "__xor__", "__rxor__"),
+    ("<<", "__lshift__", "__rlshift__"),
+    (">>", "__rshift__", "__rrshift__"),
+    ("==", "__eq__", "__eq__"),
+    ("!=", "__ne__", "__ne__"),
+    ("<", "__lt__", "__gt__"),
+    (">", "__gt__", "__lt__"),
+    ("<=", "__le__", "__ge__"),
+    (">=", "__ge__", "__le__"),
+];
+
+pub fn special_methods(operator: &str) -> Option<(&'static str, &'static str)> {
+    SPECIAL_METHODS
+        .iter()
+        .find(|(op, _, _)| *op == operator)
+        .map(|(_, method, reflected)| (*method, *reflected))
+}
+
+pub fn is_binary_operator(operator: &str) -> bool {
+    BINARY_RULES.iter().any(|rule| rule.operator == operator)
+}
+
+pub fn apply_binary(left: &Value, operator: &str, right: &Value) -> Result<Value> {
+    let (left_type, right_type) = (type_name(left), type_name(right));
+    let rule = BINARY_RULES
+        .iter()
+        .find(|rule| rule.operator == operator && rule.left == left_type && rule.right == right_type);
+    match rule {
+        Some(rule) => (rule.apply)(left, right),
+        None if operator == "+" && (left_type == "str" || right_type == "str") => {
+            let other = if left_type == "str" { right_type } else { left_type };
+            Err(Exception::type_error(format!(
+                "Zeichenketten können nur mit Zeichenketten verkettet werden, nicht mit '{}'; \
+                 wandle den Wert mit str(...) um",
+                other
+            )))
+        }
+        None => Err(Exception::type_error(format!(
+            "Ungültige Operandentypen für '{}': '{}' und '{}'",
+            operator, left_type, right_type
+        ))),
+    }
+}
+
+fn num(value: &Value) -> f64 {
+    match value {
+        Value::Number(n) => *n,
+        _ => unreachable!(),
+    }
+}
+
+fn divisor(value: &Value) -> Result<f64> {
+    let r = num(value);
+    if r == 0.0 {
+        return Err(Exception::zero_division());
+    }
+    Ok(r)
+}
+
+// Bitweise Operatoren arbeiten nur auf ganzen Zahlen
+fn int(value: &Value, operator: &str) -> Result<i64> {
+    let n = num(value);
+    whole_number(n)?.ok_or_else(|| {
+        Exception::type_error(format!(
+            "Operator '{}' erwartet ganze Zahlen, nicht {}",
+            operator, n
+        ))
+    })
+}
+
+fn shift(value: &Value, operator: &str) -> Result<i64> {
+    let r = int(value, operator)?;
+    if r < 0 {
+        return Err(Exception::value_error(format!("Negative Verschiebungsweite: {}", r)));
+    }
+    Ok(r)
+}
+
+// Wiederholungsanzahl für Sequenzen; negative Werte ergeben eine leere Sequenz
+fn count(value: &Value) -> Result<usize> {
+    let n = int(value, "*")?;
+    Ok(n.max(0) as usize)
+}
+
+// Länge des Ergebnisses einer Wiederholung; zu große Ergebnisse sind ein Fehler statt eines
+// Absturzes beim Reservieren
+fn repeated_len(len: usize, times: usize) -> Result<usize> {
+    len.checked_mul(times)
+        .filter(|total| *total <= isize::MAX as usize)
+        .ok_or_else(|| Exception::new("OverflowError", "Wiederholte Sequenz wäre zu lang"))
+}
+
+fn repeat(sequence: &Value, times: &Value) -> Result<Vec<Value>> {
+    let items = items(sequence);
+    let times = count(times)?;
+    let mut result = Vec::new();
+    if items.is_empty() {
+        return Ok(result);
+    }
+    result
+        .try_reserve_exact(repeated_len(items.len(), times)?)
+        .map_err(|_| Exception::memory_error())?;
+    for _ in 0..times {
+        result.extend(items.iter().cloned());
+    }
+    Ok(result)
+}
+
+fn repeat_string(s: &Value, times: &Value) -> Result<String> {
+    let s = string(s);
+    let times = count(times)?;
+    let mut result = String::new();
+    if s.is_empty() {
+        return Ok(result);
+    }
+    result
+        .try_reserve_exact(repeated_len(s.len(), times)?)
+        .map_err(|_| Exception::memory_error())?;
+    for _ in 0..times {
+        result.push_str(s);
+    }
+    Ok(result)
+}
+
+fn string(value: &Value) -> &str {
+    match value {
+        Value::String(s) => s,
+        _ => unreachable!(),
+    }
+}
+
+fn items(value: &Value) -> Vec<Value> {
+    match value {
+        Value::List(items) => items.borrow().clone(),
+        Value::Tuple(items) => items.clone(),
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn n(value: f64) -> Value {
+        Value::Number(value)
+    }
+
+    fn s(value: &str) -> Value {
+        Value::String(value.to_string())
+    }
+
+    fn apply(left: Value, operator: &str, right: Value) -> Value {
+        apply_binary(&left, operator, &right).expect("Operator sollte gelingen")
+    }
+
+    fn error_kind(left: Value, operator: &str, right: Value) -> &'static str {
+        match apply_binary(&left, operator, &right) {
+            Err(Exception::Builtin { kind, .. }) => kind,
+            other => panic!("Fehler erwartet, erhalten {:?}", other),
+        }
+    }
+
+    #[test]
+    fn arithmetic_on_numbers() {
+        assert_eq!(apply(n(2.0), "+", n(3.0)), n(5.0));
+        assert_eq!(apply(n(2.0), "-", n(3.0)), n(-1.0));
+        assert_eq!(apply(n(2.0), "*", n(3.0)), n(6.0));
+        assert_eq!(apply(n(7.0), "/", n(2.0)), n(3.5));
+        assert_eq!(apply(n(2.0), "**", n(10.0)), n(1024.0));
+    }
+
+    #[test]
+    fn floor_division_and_modulo_follow_the_divisor() {
+        assert_eq!(apply(n(-7.0), "//", n(2.0)), n(-4.0));
+        assert_eq!(apply(n(-7.0), "%", n(3.0)), n(2.0));
+        assert_eq!(apply(n(7.0), "%", n(-3.0)), n(-2.0));
+    }
+
+    #[test]
+    fn division_by_zero() {
+        assert_eq!(error_kind(n(1.0), "/", n(0.0)), "ZeroDivisionError");
+        assert_eq!(error_kind(n(1.0), "//", n(0.0)), "ZeroDivisionError");
+        assert_eq!(error_kind(n(1.0), "%", n(0.0)), "ZeroDivisionError");
+        assert_eq!(error_kind(n(0.0), "**", n(-1.0)), "ZeroDivisionError");
+    }
+
+    #[test]
+    fn bitwise_operators() {
+        assert_eq!(apply(n(6.0), "&", n(3.0)), n(2.0));
+        assert_eq!(apply(n(6.0), "|", n(3.0)), n(7.0));
+        assert_eq!(apply(n(6.0), "^", n(3.0)), n(5.0));
+        assert_eq!(apply(n(1.0), "<<", n(4.0)), n(16.0));
+        assert_eq!(apply(n(-16.0), ">>", n(2.0)), n(-4.0));
+        assert_eq!(error_kind(n(1.5), "&", n(1.0)), "TypeError");
+        assert_eq!(error_kind(n(1.0), "<<", n(-1.0)), "ValueError");
+        assert_eq!(error_kind(n(1.0), "<<", n(64.0)), "OverflowError");
+    }
+
+    #[test]
+    fn bitwise_operands_beyond_i64_are_overflow_errors() {
+        let huge = 2f64.powi(70);
+        assert_eq!(error_kind(n(huge), "|", n(0.0)), "OverflowError");
+        assert_eq!(error_kind(n(0.0), "&", n(-huge)), "OverflowError");
+        assert_eq!(error_kind(n(1.0), "<<", n(huge)), "OverflowError");
+        // -2^63 ist noch darstellbar
+        let min = -(2f64.powi(63));
+        assert_eq!(apply(n(min), "|", n(0.0)), n(min));
+    }
+
+    #[test]
+    fn concatenation() {
+        assert_eq!(apply(s("ab"), "+", s("cd")), s("abcd"));
+        assert_eq!(
+            apply(Value::list(vec![n(1.0)]), "+", Value::list(vec![n(2.0)])),
+            Value::list(vec![n(1.0), n(2.0)])
+        );
+        assert_eq!(
+            apply(Value::Tuple(vec![n(1.0)]), "+", Value::Tuple(vec![n(2.0)])),
+            Value::Tuple(vec![n(1.0), n(2.0)])
+        );
+    }
+
+    #[test]
+    fn repetition_on_either_side() {
+        assert_eq!(apply(s("ab"), "*", n(3.0)), s("ababab"));
+        assert_eq!(apply(n(2.0), "*", s("x")), s("xx"));
+        assert_eq!(apply(s("ab"), "*", n(-1.0)), s(""));
+        assert_eq!(
+            apply(n(2.0), "*", Value::list(vec![n(1.0)])),
+            Value::list(vec![n(1.0), n(1.0)])
+        );
+        assert_eq!(apply(Value::Tuple(vec![n(1.0)]), "*", n(0.0)), Value::Tuple(Vec::new()));
+        assert_eq!(apply(Value::list(Vec::new()), "*", n(5e18)), Value::list(Vec::new()));
+    }
+
+    #[test]
+    fn oversized_repetition_is_an_exception() {
+        let huge = n(5e18);
+        let kind = error_kind(Value::list(vec![n(1.0), n(2.0)]), "*", huge.clone());
+        assert!(matches!(kind, "OverflowError" | "MemoryError"), "{}", kind);
+        let kind = error_kind(s("ab"), "*", huge);
+        assert!(matches!(kind, "OverflowError" | "MemoryError"), "{}", kind);
+    }
+
+    #[test]
+    fn mismatched_types_are_type_errors() {
+        assert_eq!(error_kind(s("a"), "+", n(1.0)), "TypeError");
+        assert_eq!(error_kind(n(1.0), "-", s("a")), "TypeError");
+        assert_eq!(error_kind(Value::list(Vec::new()), "*", n(1.5)), "TypeError");
+        assert_eq!(error_kind(Value::Null, "+", Value::Null), "TypeError");
+    }
+
+    #[test]
+    fn every_rule_computes_its_result() {
+        let list = |values: &[f64]| Value::list(values.iter().map(|v| n(*v)).collect());
+        let tuple = |values: &[f64]| Value::Tuple(values.iter().map(|v| n(*v)).collect());
+        let cases = [
+            (n(2.0), "+", n(3.0), n(5.0)),
+            (s("ab"), "+", s("c"), s("abc")),
+            (list(&[1.0]), "+", list(&[2.0]), list(&[1.0, 2.0])),
+            (tuple(&[1.0]), "+", tuple(&[2.0]), tuple(&[1.0, 2.0])),
+            (n(2.0), "-", n(3.0), n(-1.0)),
+            (n(2.0), "*", n(3.0), n(6.0)),
+            (s("ab"), "*", n(2.0), s("abab")),
+            (n(2.0), "*", s("ab"), s("abab")),
+            (list(&[1.0]), "*", n(2.0), list(&[1.0, 1.0])),
+            (n(2.0), "*", list(&[1.0]), list(&[1.0, 1.0])),
+            (tuple(&[1.0]), "*", n(2.0), tuple(&[1.0, 1.0])),
+            (n(2.0), "*", tuple(&[1.0]), tuple(&[1.0, 1.0])),
+            (n(7.0), "/", n(2.0), n(3.5)),
+            (n(7.0), "//", n(2.0), n(3.0)),
+            (n(-7.0), "%", n(3.0), n(2.0)),
+            (n(2.0), "**", n(10.0), n(1024.0)),
+            (n(12.0), "&", n(10.0), n(8.0)),
+            (n(12.0), "|", n(10.0), n(14.0)),
+            (n(12.0), "^", n(10.0), n(6.0)),
+            (n(3.0), "<<", n(2.0), n(12.0)),
+            (n(-12.0), ">>", n(2.0), n(-3.0)),
+        ];
+        for (left, operator, right, expected) in &cases {
+            assert_eq!(&apply(left.clone(), operator, right.clone()), expected, "{}", operator);
+        }
+        // Jede Regel ist durch genau einen Fall abgedeckt
+        for rule in BINARY_RULES {
+            let covered = cases
+                .iter()
+                .filter(|(left, operator, right, _)| {
+                    *operator == rule.operator
+                        && type_name(left) == rule.left
+                        && type_name(right) == rule.right
+                })
+                .count();
+            assert_eq!(covered, 1, "Regel '{} {} {}'", rule.left, rule.operator, rule.right);
+        }
+        assert_eq!(cases.len(), BINARY_RULES.len());
+    }
+}
@@ -1,24 +1,43 @@
 // src/parser.rs
 
-use crate::ast::ASTNode;
-use crate::token::Token;
+use crate::ast::{ASTNode, ComprehensionClause, ExceptHandler};
+use crate::lexer::Lexer;
+use crate::token::{Span, Token};
 
 pub struct Parser {
     tokens: Vec<Token>,
+    spans: Vec<Span>, // Position jedes Tokens, parallel zu `tokens`
     position: usize,
     current_token: Token,
 }
 
 impl Parser {
-    pub fn new(tokens: Vec<Token>) -> Self {
-        let first_token = tokens.get(0).cloned().unwrap_or(Token::EOF);
+    pub fn new(tokens: Vec<Token>, spans: Vec<Span>) -> Self {
+        let first_token = tokens.first().cloned().unwrap_or(Token::EOF);
         Self {
             tokens,
+            spans,
             position: 0,
             current_token: first_token,
         }
     }
 
+    // Zerlegt den Quelltext in Tokens und merkt sich zu jedem seine Position
+    pub fn from_source(code: &str, file: usize) -> Self {
+        let mut lexer = Lexer::new(code.to_string(), file);
+        let mut tokens = Vec::new();
+        let mut spans = Vec::new();
+        loop {
+            let token = lexer.next_token();
+            if token == Token::EOF {
+                break;
+            }
+            tokens.push(token);
+            spans.push(lexer.span());
+        }
+        Self::new(tokens, spans)
+    }
+
     fn advance(&mut self) {
         loop {
             self.position += 1;
@@ -58,24 +77,27 @@ impl Parser {
         ASTNode::Program(statements)
     }
 
+    // Jede Anweisung merkt sich ihre Position für Tracebacks
     fn parse_statement(&mut self) -> ASTNode {
-        match &self.current_token {
+        let span = self.span();
+        let node = match &self.current_token {
             Token::If => self.parse_if_statement(),
             Token::While => self.parse_while_loop(),
             Token::Def => self.parse_function_def(),
+            Token::Class => self.parse_class_def(),
             Token::Return => self.parse_return_statement(),
+            Token::Try => self.parse_try_statement(),
+            Token::Raise => self.parse_raise_statement(),
+            Token::Assert => self.parse_assert_statement(),
+            Token::Import => self.parse_import_statement(),
+            Token::From => self.parse_from_import_statement(),
+            Token::With => self.parse_with_statement(),
             Token::Print => self.parse_print_statement(),
-            _ => {
-                if let Token::Identifier(_) = &self.current_token {
-                    if self.peek_token() == Token::Assign {
-                        self.parse_assignment()
-                    } else {
-                        self.parse_expression_statement()
-                    }
-                } else {
-                    self.parse_expression_statement()
-                }
-            }
+            _ => self.parse_expression_statement(),
+        };
+        ASTNode::Statement {
+            span,
+            node: Box::new(node),
         }
     }
 
@@ -161,6 +183,201 @@ impl Parser {
         }
     }
 
+    fn parse_class_def(&mut self) -> ASTNode {
+        self.advance(); // 'class'
+
+        let name = if let Token::Identifier(name) = &self.current_token {
+            name.clone()
+        } else {
+            panic!("Klassenname erwartet");
+        };
+        self.advance();
+
+        // Optionale Basisklasse: `class Circle(Shape):`
+        let mut base = None;
+        if self.current_token == Token::LeftParen {
+            self.advance();
+            if self.current_token != Token::RightParen {
+                base = Some(Box::new(self.parse_expression()));
+                if self.current_token == Token::Comma {
+                    panic!("Mehrfachvererbung wird nicht unterstützt");
+                }
+            }
+            self.expect(Token::RightParen);
+        }
+
+        self.expect(Token::Colon);
+
+        let body = self.parse_block();
+
+        ASTNode::ClassDef {
+            name,
+            base,
+            body: Box::new(body),
+        }
+    }
+
+    fn parse_try_statement(&mut self) -> ASTNode {
+        self.advance(); // 'try'
+        self.expect(Token::Colon);
+        let body = self.parse_block();
+
+        let mut handlers = Vec::new();
+        while self.current_token == Token::Except {
+            self.advance(); // 'except'
+            let mut exception_type = None;
+            let mut name = None;
+            if self.current_token != Token::Colon {
+                exception_type = Some(self.parse_expression());
+                if self.current_token == Token::As {
+                    self.advance();
+                    if let Token::Identifier(n) = &self.current_token {
+                        name = Some(n.clone());
+                        self.advance();
+                    } else {
+                        panic!("Name nach 'as' erwartet");
+                    }
+                }
+            } else if handlers.iter().any(|h: &ExceptHandler| h.exception_type.is_none()) {
+                panic!("Nur ein 'except:' ohne Typ ist erlaubt");
+            }
+            self.expect(Token::Colon);
+            let body = self.parse_block();
+            handlers.push(ExceptHandler {
+                exception_type,
+                name,
+                body,
+            });
+        }
+
+        let else_branch = if self.current_token == Token::Else {
+            if handlers.is_empty() {
+                panic!("'else' nach 'try' erfordert mindestens einen 'except'-Zweig");
+            }
+            self.advance(); // 'else'
+            self.expect(Token::Colon);
+            Some(Box::new(self.parse_block()))
+        } else {
+            None
+        };
+
+        let finally_branch = if self.current_token == Token::Finally {
+            self.advance(); // 'finally'
+            self.expect(Token::Colon);
+            Some(Box::new(self.parse_block()))
+        } else {
+            None
+        };
+
+        if handlers.is_empty() && finally_branch.is_none() {
+            panic!("'try' erfordert 'except' oder 'finally'");
+        }
+
+        ASTNode::TryStatement {
+            body: Box::new(body),
+            handlers,
+            else_branch,
+            finally_branch,
+        }
+    }
+
+    fn parse_with_statement(&mut self) -> ASTNode {
+        self.advance(); // 'with'
+        let context = self.parse_expression();
+        let name = if self.current_token == Token::As {
+            self.advance();
+            Some(self.expect_identifier("Name nach 'as'"))
+        } else {
+            None
+        };
+        self.expect(Token::Colon);
+        let body = self.parse_block();
+        ASTNode::With {
+            context: Box::new(context),
+            name,
+            body: Box::new(body),
+        }
+    }
+
+    fn parse_raise_statement(&mut self) -> ASTNode {
+        self.advance(); // 'raise'
+        if !self.on_same_line() || matches!(self.current_token, Token::Dedent | Token::EOF) {
+            return ASTNode::Raise(None);
+        }
+        let value = self.parse_expression();
+        ASTNode::Raise(Some(Box::new(value)))
+    }
+
+    fn parse_import_statement(&mut self) -> ASTNode {
+        self.advance(); // 'import'
+        let mut modules = Vec::new();
+        loop {
+            let name = self.expect_identifier("Modulname");
+            modules.push((name, self.parse_import_alias()));
+            if self.current_token != Token::Comma {
+                break;
+            }
+            self.advance();
+        }
+        ASTNode::Import(modules)
+    }
+
+    fn parse_from_import_statement(&mut self) -> ASTNode {
+        self.advance(); // 'from'
+        let module = self.expect_identifier("Modulname");
+        self.expect(Token::Import);
+        let mut names = Vec::new();
+        loop {
+            let name = self.expect_identifier("Name");
+            names.push((name, self.parse_import_alias()));
+            if self.current_token != Token::Comma {
+                break;
+            }
+            self.advance();
+        }
+        ASTNode::FromImport { module, names }
+    }
+
+    // Optionales `as name` hinter einem importierten Namen
+    fn parse_import_alias(&mut self) -> Option<String> {
+        if self.current_token != Token::As {
+            return None;
+        }
+        self.advance();
+        Some(self.expect_identifier("Name nach 'as'"))
+    }
+
+    fn expect_identifier(&mut self, what: &str) -> String {
+        let Token::Identifier(name) = &self.current_token else {
+            panic!("{} erwartet, gefunden: {:?}", what, self.current_token);
+        };
+        let name = name.clone();
+        self.advance();
+        name
+    }
+
+    fn parse_assert_statement(&mut self) -> ASTNode {
+        self.advance(); // 'assert'
+        let start = self.span();
+        let condition = self.parse_expression();
+        let end = self.previous_span();
+        let message = if self.current_token == Token::Comma {
+            self.advance();
+            Some(Box::new(self.parse_expression()))
+        } else {
+            None
+        };
+        ASTNode::Assert {
+            condition: Box::new(condition),
+            message,
+            span: Span {
+                end_line: end.end_line,
+                end_column: end.end_column,
+                ..start
+            },
+        }
+    }
+
     fn parse_return_statement(&mut self) -> ASTNode {
         self.advance(); // 'return'
         let value = self.parse_expression();
@@ -173,24 +390,91 @@ impl Parser {
         ASTNode::Print(Box::new(value))
     }
 
-    fn parse_assignment(&mut self) -> ASTNode {
-        if let Token::Identifier(name) = &self.current_token {
-            let var_name = name.clone();
+    fn parse_expression_statement(&mut self) -> ASTNode {
+        let expr = self.parse_expression_list();
+        if self.current_token == Token::Assign {
+            let mut targets = vec![expr];
+            let value = loop {
+                self.advance(); // '='
+                let expr = self.parse_expression_list();
+                if self.current_token == Token::Assign {
+                    targets.push(expr);
+                } else {
+                    break expr;
+                }
+            };
+            for target in &targets {
+                self.check_assignment_target(target, false);
+            }
+            return ASTNode::Assignment {
+                targets,
+                value: Box::new(value),
+            };
+        }
+        if let Token::AugAssign(op) = &self.current_token {
+            let operator = op.clone();
+            if !matches!(
+                expr,
+                ASTNode::Variable(_) | ASTNode::Index { .. } | ASTNode::Attribute { .. }
+            ) {
+                panic!("Ungültiges Ziel für erweiterte Zuweisung: {:?}", expr);
+            }
+            self.advance();
+            let value = self.parse_expression();
+            return ASTNode::AugAssignment {
+                target: Box::new(expr),
+                operator,
+                value: Box::new(value),
+            };
+        }
+        expr
+    }
+
+    // Ausdrucksliste auf Anweisungsebene: `a, b` ergibt ein Tupel
+    fn parse_expression_list(&mut self) -> ASTNode {
+        let first = self.parse_starred_expression();
+        if self.current_token != Token::Comma {
+            return first;
+        }
+        let mut elements = vec![first];
+        while self.current_token == Token::Comma {
             self.advance();
-            self.expect(Token::Assign);
-            let expr = self.parse_expression();
-            ASTNode::Assignment {
-                name: var_name,
-                value: Box::new(expr),
+            // Erlaube trailing comma: `a, = xs`
+            if self.current_token == Token::Assign || !self.on_same_line() {
+                break;
             }
-        } else {
-            panic!("Variable erwartet");
+            elements.push(self.parse_starred_expression());
         }
+        ASTNode::Tuple(elements)
     }
 
-    fn parse_expression_statement(&mut self) -> ASTNode {
-        let expr = self.parse_expression();
-        ASTNode::Statement(Box::new(expr))
+    fn parse_starred_expression(&mut self) -> ASTNode {
+        if self.current_token == Token::Operator("*".to_string()) {
+            self.advance(); // '*'
+            let expr = self.parse_expression();
+            return ASTNode::Starred(Box::new(expr));
+        }
+        self.parse_expression()
+    }
+
+    fn check_assignment_target(&self, target: &ASTNode, nested: bool) {
+        match target {
+            ASTNode::Variable(_) | ASTNode::Index { .. } | ASTNode::Attribute { .. } => {}
+            ASTNode::Starred(inner) if nested => self.check_assignment_target(inner, true),
+            ASTNode::Tuple(elements) | ASTNode::List(elements) => {
+                let starred = elements
+                    .iter()
+                    .filter(|e| matches!(e, ASTNode::Starred(_)))
+                    .count();
+                if starred > 1 {
+                    panic!("Mehrere Stern-Ausdrücke in einer Zuweisung");
+                }
+                for element in elements {
+                    self.check_assignment_target(element, true);
+                }
+            }
+            _ => panic!("Ungültiges Zuweisungsziel: {:?}", target),
+        }
     }
 
     fn parse_block(&mut self) -> ASTNode {
@@ -221,8 +505,78 @@ impl Parser {
 
 
 
+    // Operatorrangfolge, von schwach nach stark bindend:
+    //
+    //   lambda
+    //   a if b else c
+    //   or
+    //   and
+    //   not
+    //   == != < > <= >= in, not in, is, is not   (verkettbar)
+    //   |
+    //   ^
+    //   &
+    //   << >>
+    //   + -
+    //   * / // %
+    //   - + ~          (unär)
+    //   **             (rechtsassoziativ, bindet stärker als ein unäres Minus links davon)
+    //   Aufruf, Index, Atome
     fn parse_expression(&mut self) -> ASTNode {
-        self.parse_logic_or()
+        if self.current_token == Token::Lambda {
+            return self.parse_lambda();
+        }
+        let node = self.parse_logic_or();
+
+        if self.current_token == Token::If && self.on_same_line() {
+            self.advance(); // 'if'
+            let condition = self.parse_logic_or();
+            self.expect(Token::Else);
+            let else_expr = self.parse_expression();
+            return ASTNode::ConditionalExpression {
+                condition: Box::new(condition),
+                then_expr: Box::new(node),
+                else_expr: Box::new(else_expr),
+            };
+        }
+
+        node
+    }
+
+    fn parse_lambda(&mut self) -> ASTNode {
+        self.advance(); // 'lambda'
+
+        let mut params = Vec::new();
+        if self.current_token != Token::Colon {
+            loop {
+                if let Token::Identifier(name) = &self.current_token {
+                    params.push(name.clone());
+                    self.advance();
+                } else {
+                    panic!("Parametername erwartet");
+                }
+                if self.current_token == Token::Comma {
+                    self.advance();
+                } else {
+                    break;
+                }
+            }
+        }
+
+        self.expect(Token::Colon);
+
+        // Der Rumpf trägt seine Position wie eine Anweisung, damit Tracebacks den
+        // Rahmen des Lambdas an der richtigen Stelle zeigen
+        let span = self.span();
+        let body = self.parse_expression();
+
+        ASTNode::Lambda {
+            params,
+            body: Box::new(ASTNode::Statement {
+                span,
+                node: Box::new(body),
+            }),
+        }
     }
 
     fn parse_logic_or(&mut self) -> ASTNode {
@@ -242,11 +596,11 @@ impl Parser {
     }
 
     fn parse_logic_and(&mut self) -> ASTNode {
-        let mut node = self.parse_equality();
+        let mut node = self.parse_logic_not();
 
         while self.current_token == Token::And {
             self.advance();
-            let right = self.parse_equality();
+            let right = self.parse_logic_not();
             node = ASTNode::BinaryOp {
                 left: Box::new(node),
                 operator: "and".to_string(),
@@ -257,35 +611,101 @@ impl Parser {
         node
     }
 
-    fn parse_equality(&mut self) -> ASTNode {
-        let mut node = self.parse_comparison();
+    fn parse_logic_not(&mut self) -> ASTNode {
+        if self.current_token == Token::Not {
+            self.advance();
+            let operand = self.parse_logic_not();
+            return ASTNode::UnaryOp {
+                operator: "not".to_string(),
+                operand: Box::new(operand),
+            };
+        }
+        self.parse_comparison()
+    }
 
-        while let Token::Operator(op) = &self.current_token {
-            if op == "==" || op == "!=" {
-                let operator = op.clone();
-                self.advance();
-                let right = self.parse_comparison();
-                node = ASTNode::BinaryOp {
-                    left: Box::new(node),
-                    operator,
-                    right: Box::new(right),
-                };
-            } else {
-                break;
+    // Vergleiche lassen sich verketten: `a < b <= c` entspricht `a < b and b <= c`
+    fn parse_comparison(&mut self) -> ASTNode {
+        let left = self.parse_bitwise_or();
+
+        let mut comparisons = Vec::new();
+        while let Some(operator) = self.comparison_operator() {
+            let right = self.parse_bitwise_or();
+            comparisons.push((operator, right));
+        }
+
+        if comparisons.len() == 1 {
+            let (operator, right) = comparisons.pop().unwrap();
+            ASTNode::BinaryOp {
+                left: Box::new(left),
+                operator,
+                right: Box::new(right),
+            }
+        } else if comparisons.is_empty() {
+            left
+        } else {
+            ASTNode::Comparison {
+                left: Box::new(left),
+                comparisons,
             }
         }
+    }
 
-        node
+    // Liest einen Vergleichsoperator, inklusive `not in` und `is not`
+    fn comparison_operator(&mut self) -> Option<String> {
+        if !self.on_same_line() {
+            return None;
+        }
+        let operator = match &self.current_token {
+            Token::Operator(op) if ["==", "!=", "<", ">", "<=", ">="].contains(&op.as_str()) => {
+                op.clone()
+            }
+            Token::In => "in".to_string(),
+            Token::Is => {
+                if self.peek_token() == Token::Not {
+                    self.advance();
+                    "is not".to_string()
+                } else {
+                    "is".to_string()
+                }
+            }
+            Token::Not if self.peek_token() == Token::In => {
+                self.advance();
+                "not in".to_string()
+            }
+            _ => return None,
+        };
+        self.advance();
+        Some(operator)
     }
 
-    fn parse_comparison(&mut self) -> ASTNode {
-        let mut node = self.parse_term();
+    fn parse_bitwise_or(&mut self) -> ASTNode {
+        self.parse_left_associative(&["|"], Self::parse_bitwise_xor)
+    }
+
+    fn parse_bitwise_xor(&mut self) -> ASTNode {
+        self.parse_left_associative(&["^"], Self::parse_bitwise_and)
+    }
+
+    fn parse_bitwise_and(&mut self) -> ASTNode {
+        self.parse_left_associative(&["&"], Self::parse_shift)
+    }
+
+    fn parse_shift(&mut self) -> ASTNode {
+        self.parse_left_associative(&["<<", ">>"], Self::parse_term)
+    }
+
+    fn parse_left_associative(
+        &mut self,
+        operators: &[&str],
+        next: fn(&mut Self) -> ASTNode,
+    ) -> ASTNode {
+        let mut node = next(self);
 
         while let Token::Operator(op) = &self.current_token {
-            if ["<", ">", "<=", ">="].contains(&op.as_str()) {
+            if operators.contains(&op.as_str()) && self.on_same_line() {
                 let operator = op.clone();
                 self.advance();
-                let right = self.parse_term();
+                let right = next(self);
                 node = ASTNode::BinaryOp {
                     left: Box::new(node),
                     operator,
@@ -303,7 +723,7 @@ impl Parser {
         let mut node = self.parse_factor();
 
         while let Token::Operator(op) = &self.current_token {
-            if op == "+" || op == "-" {
+            if (op == "+" || op == "-") && self.on_same_line() {
                 let operator = op.clone();
                 self.advance();
                 let right = self.parse_factor();
@@ -324,7 +744,7 @@ impl Parser {
         let mut node = self.parse_unary();
 
         while let Token::Operator(op) = &self.current_token {
-            if op == "*" || op == "/" || op == "%" {
+            if (op == "*" || op == "/" || op == "//" || op == "%") && self.on_same_line() {
                 let operator = op.clone();
                 self.advance();
                 let right = self.parse_unary();
@@ -343,7 +763,7 @@ impl Parser {
 
     fn parse_unary(&mut self) -> ASTNode {
         if let Token::Operator(op) = &self.current_token {
-            if op == "-" || op == "+" {
+            if op == "-" || op == "+" || op == "~" {
                 let operator = op.clone();
                 self.advance();
                 let operand = self.parse_unary();
@@ -352,16 +772,115 @@ impl Parser {
                     operand: Box::new(operand),
                 };
             }
-        } else if self.current_token == Token::Not {
-            let operator = "not".to_string();
+        }
+        self.parse_power()
+    }
+
+    fn parse_power(&mut self) -> ASTNode {
+        let base = self.parse_postfix();
+
+        if self.current_token == Token::Operator("**".to_string()) && self.on_same_line() {
             self.advance();
-            let operand = self.parse_unary();
-            return ASTNode::UnaryOp {
-                operator,
-                operand: Box::new(operand),
+            // Rechtsassoziativ: der Exponent darf selbst unär sein (2 ** -1)
+            let exponent = self.parse_unary();
+            return ASTNode::BinaryOp {
+                left: Box::new(base),
+                operator: "**".to_string(),
+                right: Box::new(exponent),
             };
         }
-        self.parse_atom()
+
+        base
+    }
+
+    fn parse_postfix(&mut self) -> ASTNode {
+        let mut node = self.parse_atom();
+
+        // Aufrufe, Indizes und Attribute binden nur innerhalb derselben Zeile
+        while self.on_same_line() {
+            match self.current_token {
+                Token::LeftParen => {
+                    node = self.parse_call(node);
+                }
+                Token::LeftBracket => {
+                    self.advance(); // '['
+                    let index = self.parse_expression();
+                    self.expect(Token::RightBracket);
+                    node = ASTNode::Index {
+                        object: Box::new(node),
+                        index: Box::new(index),
+                    };
+                }
+                Token::Dot => {
+                    self.advance(); // '.'
+                    let name = if let Token::Identifier(name) = &self.current_token {
+                        name.clone()
+                    } else {
+                        panic!("Attributname erwartet, gefunden: {:?}", self.current_token);
+                    };
+                    self.advance();
+                    node = ASTNode::Attribute {
+                        object: Box::new(node),
+                        name,
+                    };
+                }
+                _ => break,
+            }
+        }
+
+        node
+    }
+
+    fn parse_call(&mut self, callee: ASTNode) -> ASTNode {
+        let span = self.span();
+        self.advance(); // '('
+        let mut args = Vec::new();
+        let mut kwargs = Vec::new();
+        if self.current_token != Token::RightParen {
+            loop {
+                if let Token::Identifier(name) = &self.current_token {
+                    if self.peek_token() == Token::Assign {
+                        // Schlüsselwortargument: name=ausdruck
+                        let name = name.clone();
+                        self.advance();
+                        self.advance(); // '='
+                        let value = self.parse_expression();
+                        kwargs.push((name, value));
+                        if self.current_token == Token::Comma {
+                            self.advance();
+                            continue;
+                        }
+                        break;
+                    }
+                }
+                if !kwargs.is_empty() {
+                    panic!("Positionsargument nach Schlüsselwortargument");
+                }
+                let arg = self.parse_expression();
+                if args.is_empty() && self.current_token == Token::For {
+                    // Generator als einziges Argument: f(x for x in xs)
+                    let clauses = self.parse_comprehension_clauses();
+                    args.push(ASTNode::GeneratorExpression {
+                        element: Box::new(arg),
+                        clauses,
+                    });
+                    break;
+                }
+                args.push(arg);
+                if self.current_token == Token::Comma {
+                    self.advance();
+                } else {
+                    break;
+                }
+            }
+        }
+        self.expect(Token::RightParen);
+        ASTNode::FunctionCall {
+            callee: Box::new(callee),
+            args,
+            kwargs,
+            span,
+        }
     }
 
     fn parse_atom(&mut self) -> ASTNode {
@@ -384,39 +903,29 @@ impl Parser {
                 self.advance();
                 ASTNode::Boolean(false)
             }
+            Token::None => {
+                self.advance();
+                ASTNode::Null
+            }
             Token::Identifier(name) => {
-                let var_name = name.clone();
+                let node = ASTNode::Variable(name.clone());
                 self.advance();
-                if self.current_token == Token::LeftParen {
-                    // Funktionsaufruf
-                    self.advance(); // '('
-                    let mut args = Vec::new();
-                    if self.current_token != Token::RightParen {
-                        loop {
-                            let arg = self.parse_expression();
-                            args.push(arg);
-                            if self.current_token == Token::Comma {
-                                self.advance();
-                            } else {
-                                break;
-                            }
-                        }
-                    }
-                    self.expect(Token::RightParen);
-                    ASTNode::FunctionCall {
-                        name: var_name,
-                        args,
-                    }
-                } else {
-                    ASTNode::Variable(var_name)
-                }
+                node
             }
             Token::LeftParen => {
                 self.advance();
                 let mut elements = Vec::new();
                 if self.current_token != Token::RightParen {
                     loop {
-                        let expr = self.parse_expression();
+                        let expr = self.parse_starred_expression();
+                        if elements.is_empty() && self.current_token == Token::For {
+                            let clauses = self.parse_comprehension_clauses();
+                            self.expect(Token::RightParen);
+                            return ASTNode::GeneratorExpression {
+                                element: Box::new(expr),
+                                clauses,
+                            };
+                        }
                         elements.push(expr);
                         if self.current_token == Token::Comma {
                             self.advance();
@@ -430,7 +939,7 @@ impl Parser {
                     }
                 }
                 self.expect(Token::RightParen);
-                if elements.len() == 1 {
+                if elements.len() == 1 && !matches!(elements[0], ASTNode::Starred(_)) {
                     elements[0].clone() // Kein Tupel, nur Ausdruck
                 } else {
                     ASTNode::Tuple(elements)
@@ -441,10 +950,21 @@ impl Parser {
                 let mut elements = Vec::new();
                 if self.current_token != Token::RightBracket {
                     loop {
-                        let element = self.parse_expression();
+                        let element = self.parse_starred_expression();
+                        if elements.is_empty() && self.current_token == Token::For {
+                            let clauses = self.parse_comprehension_clauses();
+                            self.expect(Token::RightBracket);
+                            return ASTNode::ListComprehension {
+                                element: Box::new(element),
+                                clauses,
+                            };
+                        }
                         elements.push(element);
                         if self.current_token == Token::Comma {
                             self.advance();
+                            if self.current_token == Token::RightBracket {
+                                break;
+                            }
                         } else {
                             break;
                         }
@@ -453,12 +973,134 @@ impl Parser {
                 self.expect(Token::RightBracket);
                 ASTNode::List(elements)
             }
+            Token::LeftBrace => self.parse_brace_literal(),
             _ => {
                 panic!("Unerwartetes Token in parse_atom: {:?}", self.current_token);
             }
         }
     }
 
+    // `{}` ist ein leeres Dict; `{k: v, ...}` ein Dict, `{a, b}` ein Set, jeweils auch als Comprehension
+    fn parse_brace_literal(&mut self) -> ASTNode {
+        self.advance(); // '{'
+        if self.current_token == Token::RightBrace {
+            self.advance();
+            return ASTNode::Dict(Vec::new());
+        }
+
+        let first = self.parse_expression();
+        if self.current_token == Token::Colon {
+            self.advance(); // ':'
+            let value = self.parse_expression();
+            if self.current_token == Token::For {
+                let clauses = self.parse_comprehension_clauses();
+                self.expect(Token::RightBrace);
+                return ASTNode::DictComprehension {
+                    key: Box::new(first),
+                    value: Box::new(value),
+                    clauses,
+                };
+            }
+            let mut entries = vec![(first, value)];
+            while self.current_token == Token::Comma {
+                self.advance();
+                if self.current_token == Token::RightBrace {
+                    break;
+                }
+                let key = self.parse_expression();
+                self.expect(Token::Colon);
+                let value = self.parse_expression();
+                entries.push((key, value));
+            }
+            self.expect(Token::RightBrace);
+            return ASTNode::Dict(entries);
+        }
+
+        if self.current_token == Token::For {
+            let clauses = self.parse_comprehension_clauses();
+            self.expect(Token::RightBrace);
+            return ASTNode::SetComprehension {
+                element: Box::new(first),
+                clauses,
+            };
+        }
+        let mut elements = vec![first];
+        while self.current_token == Token::Comma {
+            self.advance();
+            if self.current_token == Token::RightBrace {
+                break;
+            }
+            elements.push(self.parse_expression());
+        }
+        self.expect(Token::RightBrace);
+        ASTNode::Set(elements)
+    }
+
+    fn parse_comprehension_clauses(&mut self) -> Vec<ComprehensionClause> {
+        let mut clauses = Vec::new();
+        while self.current_token == Token::For {
+            self.advance(); // 'for'
+            let target = self.parse_for_target();
+            self.expect(Token::In);
+            // Ohne bedingten Ausdruck, damit ein folgendes `if` als Filter gilt
+            let iterable = self.parse_logic_or();
+            let mut conditions = Vec::new();
+            while self.current_token == Token::If {
+                self.advance(); // 'if'
+                conditions.push(self.parse_logic_or());
+            }
+            clauses.push(ComprehensionClause {
+                target,
+                iterable,
+                conditions,
+            });
+        }
+        clauses
+    }
+
+    // Schleifenziel wie `x` oder `k, v`; endet vor `in`
+    fn parse_for_target(&mut self) -> ASTNode {
+        let mut targets = vec![self.parse_bitwise_or()];
+        while self.current_token == Token::Comma {
+            self.advance();
+            if self.current_token == Token::In {
+                break;
+            }
+            targets.push(self.parse_bitwise_or());
+        }
+        let target = if targets.len() == 1 {
+            targets.pop().unwrap()
+        } else {
+            ASTNode::Tuple(targets)
+        };
+        self.check_assignment_target(&target, false);
+        target
+    }
+
+    fn on_same_line(&self) -> bool {
+        if self.position == 0 || self.position > self.tokens.len() {
+            return true;
+        }
+        !matches!(
+            self.tokens[self.position - 1],
+            Token::Newline | Token::Indent | Token::Dedent
+        )
+    }
+
+    fn span(&self) -> Span {
+        self.spans.get(self.position).copied().unwrap_or_default()
+    }
+
+    // Bereich des zuletzt verbrauchten Tokens; Zeilenumbrüche zählen nicht
+    fn previous_span(&self) -> Span {
+        let end = self.position.min(self.tokens.len());
+        self.tokens[..end]
+            .iter()
+            .rposition(|token| *token != Token::Newline)
+            .and_then(|pos| self.spans.get(pos).copied())
+            .unwrap_or_default()
+    }
+
     fn peek_token(&self) -> Token {
         if self.position + 1 >= self.tokens.len() {
             Token::EOF
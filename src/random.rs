@@ -0,0 +1,167 @@
+// src/random.rs
+
+use crate::builtins::reject_kwargs;
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::{type_name, Interpreter, Value};
+use crate::modules::{expect_args, integer, number};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub const FUNCTIONS: &[&str] = &["seed", "random", "uniform", "randint", "randrange", "choice", "shuffle"];
+
+// Pseudozufallsgenerator nach splitmix64; derselbe Startwert ergibt dieselbe Folge
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        Rng { state: seed }
+    }
+
+    pub fn from_time() -> Self {
+        Rng::new(time_seed())
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    // Gleichverteilt in [0, 1) mit 53 Bit Genauigkeit
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    // Gleichverteilt in [0, n); Werte aus dem unvollständigen letzten Block werden verworfen
+    fn below(&mut self, n: u64) -> u64 {
+        let zone = u64::MAX - u64::MAX % n;
+        loop {
+            let value = self.next_u64();
+            if value < zone {
+                return value % n;
+            }
+        }
+    }
+}
+
+pub fn call(
+    interpreter: &mut Interpreter,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    let method = format!("random.{}", name);
+    reject_kwargs(&method, &kwargs)?;
+    match name {
+        "seed" => {
+            expect_args(&method, &args, 0, 1)?;
+            let seed = match args.first() {
+                None | Some(Value::Null) => time_seed(),
+                Some(Value::Number(n)) if n.fract() == 0.0 => *n as i64 as u64,
+                Some(Value::Number(n)) => n.to_bits(),
+                // FNV-1a, damit Zeichenketten reproduzierbare Startwerte ergeben
+                Some(Value::String(s)) => s.bytes().fold(0xCBF2_9CE4_8422_2325, |hash, byte| {
+                    (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01B3)
+                }),
+                Some(other) => {
+                    return Err(Exception::type_error(format!(
+                        "random.seed() erwartet eine Zahl oder Zeichenkette, nicht '{}'",
+                        type_name(other)
+                    )))
+                }
+            };
+            interpreter.rng = Rng::new(seed);
+            Ok(Value::Null)
+        }
+        "random" => {
+            expect_args(&method, &args, 0, 0)?;
+            Ok(Value::Number(interpreter.rng.next_f64()))
+        }
+        "uniform" => {
+            expect_args(&method, &args, 2, 2)?;
+            let (a, b) = (number(&method, &args[0])?, number(&method, &args[1])?);
+            Ok(Value::Number(a + (b - a) * interpreter.rng.next_f64()))
+        }
+        "randint" => {
+            expect_args(&method, &args, 2, 2)?;
+            let (a, b) = (integer(&method, &args[0])?, integer(&method, &args[1])?);
+            if a > b {
+                return Err(Exception::value_error(format!(
+                    "random.randint(): leerer Bereich ({}, {})",
+                    a, b
+                )));
+            }
+            // Die Spanne kann bis zu 2^64 Werte umfassen und passt dann in keinen u64
+            let span = (i128::from(b) - i128::from(a)) as u64;
+            let offset = match span.checked_add(1) {
+                Some(count) => interpreter.rng.below(count),
+                None => interpreter.rng.next_u64(),
+            };
+            Ok(Value::Number((i128::from(a) + i128::from(offset)) as f64))
+        }
+        "randrange" => {
+            expect_args(&method, &args, 1, 3)?;
+            let bounds = args
+                .iter()
+                .map(|arg| integer(&method, arg))
+                .collect::<Result<Vec<_>>>()?;
+            // In i128 gerechnet, damit Bereiche über ganz i64 nicht überlaufen
+            let (start, stop, step): (i128, i128, i128) = match bounds.as_slice() {
+                [stop] => (0, (*stop).into(), 1),
+                [start, stop] => ((*start).into(), (*stop).into(), 1),
+                [start, stop, step] => ((*start).into(), (*stop).into(), (*step).into()),
+                _ => unreachable!(),
+            };
+            if step == 0 {
+                return Err(Exception::value_error("random.randrange(): Schrittweite 0"));
+            }
+            let count = (stop - start + step - step.signum()) / step;
+            if count <= 0 {
+                return Err(Exception::value_error(format!(
+                    "random.randrange(): leerer Bereich ({}, {}, {})",
+                    start, stop, step
+                )));
+            }
+            let index = i128::from(interpreter.rng.below(count as u64));
+            Ok(Value::Number((start + index * step) as f64))
+        }
+        "choice" => {
+            expect_args(&method, &args, 1, 1)?;
+            let items = interpreter.iterate(&args[0])?;
+            if items.is_empty() {
+                return Err(Exception::index_error("random.choice(): leere Sequenz"));
+            }
+            let index = interpreter.rng.below(items.len() as u64) as usize;
+            Ok(items[index].clone())
+        }
+        "shuffle" => {
+            expect_args(&method, &args, 1, 1)?;
+            let Value::List(items) = &args[0] else {
+                return Err(Exception::type_error(format!(
+                    "random.shuffle() erwartet eine Liste, nicht '{}'",
+                    type_name(&args[0])
+                )));
+            };
+            // Fisher-Yates, von hinten nach vorne
+            let mut items = items.borrow_mut();
+            for i in (1..items.len()).rev() {
+                let j = interpreter.rng.below(i as u64 + 1) as usize;
+                items.swap(i, j);
+            }
+            Ok(Value::Null)
+        }
+        _ => Err(Exception::attribute_error(format!(
+            "Modul 'random' hat kein Attribut '{}'",
+            name
+        ))),
+    }
+}
+
+fn time_seed() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_nanos() as u64)
+}
@@ -0,0 +1,819 @@
+// src/string_methods.rs
+//
+// Methoden auf Zeichenketten. Alle Positionen zählen Unicode-Zeichen, nicht Bytes.
+
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::{type_name, Interpreter, Value};
+
+pub const STRING_METHODS: &[&str] = &[
+    "upper", "lower", "casefold", "capitalize", "title", "swapcase",
+    "strip", "lstrip", "rstrip", "removeprefix", "removesuffix",
+    "split", "rsplit", "splitlines", "partition", "rpartition", "join", "replace",
+    "startswith", "endswith", "find", "rfind", "index", "rindex", "count",
+    "isdigit", "isdecimal", "isnumeric", "isalpha", "isalnum", "isspace", "isupper", "islower",
+    "istitle", "zfill", "center", "ljust", "rjust", "format",
+];
+
+pub fn call_string_method(
+    interpreter: &mut Interpreter,
+    s: &str,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    if name == "format" {
+        return Ok(Value::String(format_string(interpreter, s, &args, &kwargs)?));
+    }
+    let method = format!("str.{}", name);
+    let result = match name {
+        "upper" | "lower" | "casefold" | "capitalize" | "title" | "swapcase" => {
+            bind(&method, &[], args, kwargs)?;
+            Value::String(change_case(s, name))
+        }
+        "strip" | "lstrip" | "rstrip" => {
+            let [chars] = bind(&method, &["chars"], args, kwargs)?;
+            let chars = optional_string(&method, chars)?;
+            let is_stripped = |c: char| match &chars {
+                Some(set) => set.contains(c),
+                None => c.is_whitespace(),
+            };
+            let result = match name {
+                "strip" => s.trim_matches(is_stripped),
+                "lstrip" => s.trim_start_matches(is_stripped),
+                _ => s.trim_end_matches(is_stripped),
+            };
+            Value::String(result.to_string())
+        }
+        "removeprefix" => {
+            let [prefix] = bind(&method, &["prefix"], args, kwargs)?;
+            let prefix = required_string(&method, prefix)?;
+            Value::String(s.strip_prefix(prefix.as_str()).unwrap_or(s).to_string())
+        }
+        "removesuffix" => {
+            let [suffix] = bind(&method, &["suffix"], args, kwargs)?;
+            let suffix = required_string(&method, suffix)?;
+            Value::String(s.strip_suffix(suffix.as_str()).unwrap_or(s).to_string())
+        }
+        "split" | "rsplit" => {
+            let [sep, maxsplit] = bind(&method, &["sep", "maxsplit"], args, kwargs)?;
+            let sep = optional_string(&method, sep)?;
+            let maxsplit = optional_integer(&method, maxsplit)?.unwrap_or(-1);
+            let limit = if maxsplit < 0 { None } else { Some(maxsplit as usize) };
+            let parts = split(s, sep.as_deref(), limit, name == "rsplit")?;
+            Value::list(parts.into_iter().map(Value::String).collect())
+        }
+        "splitlines" => {
+            let [keepends] = bind(&method, &["keepends"], args, kwargs)?;
+            let keepends = match keepends {
+                Some(value) => interpreter.is_truthy(&value)?,
+                None => false,
+            };
+            Value::list(split_lines(s, keepends).into_iter().map(Value::String).collect())
+        }
+        "partition" | "rpartition" => {
+            let [sep] = bind(&method, &["sep"], args, kwargs)?;
+            let sep = required_string(&method, sep)?;
+            if sep.is_empty() {
+                return Err(Exception::value_error(format!("{}(): leeres Trennzeichen", method)));
+            }
+            let found = if name == "partition" {
+                s.find(sep.as_str())
+            } else {
+                s.rfind(sep.as_str())
+            };
+            let (head, middle, tail) = match found {
+                Some(pos) => (&s[..pos], sep.as_str(), &s[pos + sep.len()..]),
+                None if name == "partition" => (s, "", ""),
+                None => ("", "", s),
+            };
+            Value::Tuple(vec![
+                Value::String(head.to_string()),
+                Value::String(middle.to_string()),
+                Value::String(tail.to_string()),
+            ])
+        }
+        "join" => {
+            let [iterable] = bind(&method, &["iterable"], args, kwargs)?;
+            let items = interpreter.iterate(&required(&method, iterable)?)?;
+            let mut parts = Vec::new();
+            for (i, item) in items.into_iter().enumerate() {
+                match item {
+                    Value::String(part) => parts.push(part),
+                    other => {
+                        return Err(Exception::type_error(format!(
+                            "str.join(): Element {} ist vom Typ '{}', erwartet 'str'",
+                            i,
+                            type_name(&other)
+                        )))
+                    }
+                }
+            }
+            Value::String(parts.join(s))
+        }
+        "replace" => {
+            let [old, new, count] = bind(&method, &["old", "new", "count"], args, kwargs)?;
+            let old = required_string(&method, old)?;
+            let new = required_string(&method, new)?;
+            let result = match optional_integer(&method, count)? {
+                Some(count) if count >= 0 => s.replacen(old.as_str(), &new, count as usize),
+                _ => s.replace(old.as_str(), &new),
+            };
+            Value::String(result)
+        }
+        "startswith" | "endswith" => {
+            let [affix, start, end] = bind(&method, &["affix", "start", "end"], args, kwargs)?;
+            let affix = required(&method, affix)?;
+            let (window, _) = char_window(s, start, end, &method)?;
+            let candidates = match affix {
+                Value::String(a) => vec![a],
+                Value::Tuple(items) => items
+                    .into_iter()
+                    .map(|item| required_string(&method, Some(item)))
+                    .collect::<Result<_>>()?,
+                other => {
+                    return Err(Exception::type_error(format!(
+                        "{}() erwartet 'str' oder ein Tupel von 'str', nicht '{}'",
+                        method,
+                        type_name(&other)
+                    )))
+                }
+            };
+            let matched = candidates.iter().any(|a| {
+                if name == "startswith" {
+                    window.starts_with(a.as_str())
+                } else {
+                    window.ends_with(a.as_str())
+                }
+            });
+            Value::Boolean(matched)
+        }
+        "find" | "rfind" | "index" | "rindex" => {
+            let [sub, start, end] = bind(&method, &["sub", "start", "end"], args, kwargs)?;
+            let sub = required_string(&method, sub)?;
+            let (window, offset) = char_window(s, start, end, &method)?;
+            let found = if name.starts_with('r') {
+                window.rfind(sub.as_str())
+            } else {
+                window.find(sub.as_str())
+            };
+            match found {
+                Some(byte_pos) => Value::Number((offset + window[..byte_pos].chars().count()) as f64),
+                None if name.ends_with("find") => Value::Number(-1.0),
+                None => {
+                    return Err(Exception::value_error(format!(
+                        "{}(): Teilzeichenkette nicht gefunden",
+                        method
+                    )))
+                }
+            }
+        }
+        "count" => {
+            let [sub, start, end] = bind(&method, &["sub", "start", "end"], args, kwargs)?;
+            let sub = required_string(&method, sub)?;
+            let (window, _) = char_window(s, start, end, &method)?;
+            let count = if sub.is_empty() {
+                window.chars().count() + 1
+            } else {
+                window.matches(sub.as_str()).count()
+            };
+            Value::Number(count as f64)
+        }
+        "isdigit" | "isdecimal" | "isnumeric" | "isalpha" | "isalnum" | "isspace" => {
+            bind(&method, &[], args, kwargs)?;
+            let test: fn(char) -> bool = match name {
+                "isdigit" => is_digit,
+                "isdecimal" => is_decimal,
+                "isnumeric" => char::is_numeric,
+                "isalpha" => char::is_alphabetic,
+                "isalnum" => char::is_alphanumeric,
+                _ => char::is_whitespace,
+            };
+            Value::Boolean(!s.is_empty() && s.chars().all(test))
+        }
+        "isupper" | "islower" => {
+            bind(&method, &[], args, kwargs)?;
+            // Mindestens ein Buchstabe mit Groß-/Kleinschreibung, keiner in der falschen Form
+            let cased: Vec<char> = s.chars().filter(|c| c.is_uppercase() || c.is_lowercase()).collect();
+            let all_match = if name == "isupper" {
+                cased.iter().all(|c| c.is_uppercase())
+            } else {
+                cased.iter().all(|c| c.is_lowercase())
+            };
+            Value::Boolean(!cased.is_empty() && all_match)
+        }
+        "istitle" => {
+            bind(&method, &[], args, kwargs)?;
+            Value::Boolean(s.chars().any(char::is_alphabetic) && change_case(s, "title") == s)
+        }
+        "zfill" => {
+            let [width] = bind(&method, &["width"], args, kwargs)?;
+            let width = check_width(required_integer(&method, width)?.max(0) as usize)?;
+            let len = s.chars().count();
+            if len >= width {
+                return Ok(Value::String(s.to_string()));
+            }
+            let zeros = repeat_char('0', width - len)?;
+            let result = match s.chars().next() {
+                Some(sign @ ('+' | '-')) => format!("{}{}{}", sign, zeros, &s[1..]),
+                _ => format!("{}{}", zeros, s),
+            };
+            Value::String(result)
+        }
+        "center" | "ljust" | "rjust" => {
+            let [width, fillchar] = bind(&method, &["width", "fillchar"], args, kwargs)?;
+            let width = required_integer(&method, width)?.max(0) as usize;
+            let fill = match optional_string(&method, fillchar)? {
+                None => ' ',
+                Some(f) if f.chars().count() == 1 => f.chars().next().unwrap(),
+                Some(_) => {
+                    return Err(Exception::type_error(format!(
+                        "{}(): Füllzeichen muss genau ein Zeichen lang sein",
+                        method
+                    )))
+                }
+            };
+            let align = match name {
+                "center" => return Ok(Value::String(center(s, width, fill)?)),
+                "ljust" => '<',
+                _ => '>',
+            };
+            Value::String(pad(s, width, fill, align)?)
+        }
+        _ => {
+            return Err(Exception::attribute_error(format!(
+                "'str' hat keine Methode '{}'",
+                name
+            )))
+        }
+    };
+    Ok(result)
+}
+
+fn change_case(s: &str, mode: &str) -> String {
+    match mode {
+        "upper" => s.to_uppercase(),
+        "lower" => s.to_lowercase(),
+        "casefold" => casefold(s),
+        "swapcase" => s
+            .chars()
+            .map(|c| {
+                if c.is_uppercase() {
+                    c.to_lowercase().collect::<String>()
+                } else {
+                    c.to_uppercase().collect::<String>()
+                }
+            })
+            .collect(),
+        "capitalize" => {
+            let mut chars = s.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars.flat_map(char::to_lowercase)).collect(),
+                None => String::new(),
+            }
+        }
+        _ => {
+            // title: Jedes Wort beginnt groß, der Rest wird klein geschrieben
+            let mut result = String::new();
+            let mut previous_cased = false;
+            for c in s.chars() {
+                if previous_cased {
+                    result.extend(c.to_lowercase());
+                } else {
+                    result.extend(c.to_uppercase());
+                }
+                previous_cased = c.is_alphabetic();
+            }
+            result
+        }
+    }
+}
+
+// Volle Faltung wie in Python: Kleinschreibung plus die Abweichungen aus Unicode
+// CaseFolding.txt (etwa ß zu "ss", ς zu σ, Ligaturen zu Einzelbuchstaben)
+fn casefold(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        if !fold_special(c, &mut result) {
+            for lower in c.to_lowercase() {
+                if !fold_special(lower, &mut result) {
+                    result.push(lower);
+                }
+            }
+        }
+    }
+    result
+}
+
+// Hängt die Faltung von `c` an, falls sie von der Kleinschreibung abweicht
+fn fold_special(c: char, result: &mut String) -> bool {
+    let code = c as u32;
+    let folded = match code {
+        // Cherokee faltet auf die Großbuchstaben
+        0x13F8..=0x13FD => code - 8,
+        0xAB70..=0xABBF => code - 0xAB70 + 0x13A0,
+        // Griechisch mit Iota subscriptum: Grundbuchstabe ohne Iota, dann ι
+        0x1F80..=0x1FAF => {
+            let base = [0x1F00, 0x1F20, 0x1F60][(code - 0x1F80) as usize / 16] + (code & 7);
+            result.extend(char::from_u32(base));
+            result.push('\u{03B9}');
+            return true;
+        }
+        _ => match CASE_FOLDINGS.binary_search_by_key(&c, |(from, _)| *from) {
+            Ok(i) => {
+                result.push_str(CASE_FOLDINGS[i].1);
+                return true;
+            }
+            Err(_) => return false,
+        },
+    };
+    result.extend(char::from_u32(folded));
+    true
+}
+
+// Übrige Faltungen, die nicht der Kleinschreibung entsprechen, nach Zeichen sortiert
+static CASE_FOLDINGS: &[(char, &str)] = &[
+    ('\u{00B5}', "\u{03BC}"), ('\u{00DF}', "ss"), ('\u{0149}', "\u{02BC}n"), ('\u{017F}', "s"),
+    ('\u{01F0}', "j\u{030C}"), ('\u{0345}', "\u{03B9}"), ('\u{0390}', "\u{03B9}\u{0308}\u{0301}"),
+    ('\u{03B0}', "\u{03C5}\u{0308}\u{0301}"), ('\u{03C2}', "\u{03C3}"), ('\u{03D0}', "\u{03B2}"),
+    ('\u{03D1}', "\u{03B8}"), ('\u{03D5}', "\u{03C6}"), ('\u{03D6}', "\u{03C0}"),
+    ('\u{03F0}', "\u{03BA}"), ('\u{03F1}', "\u{03C1}"), ('\u{03F5}', "\u{03B5}"),
+    ('\u{0587}', "\u{0565}\u{0582}"), ('\u{1C80}', "\u{0432}"), ('\u{1C81}', "\u{0434}"),
+    ('\u{1C82}', "\u{043E}"), ('\u{1C83}', "\u{0441}"), ('\u{1C84}', "\u{0442}"),
+    ('\u{1C85}', "\u{0442}"), ('\u{1C86}', "\u{044A}"), ('\u{1C87}', "\u{0463}"),
+    ('\u{1C88}', "\u{A64B}"), ('\u{1E96}', "h\u{0331}"), ('\u{1E97}', "t\u{0308}"),
+    ('\u{1E98}', "w\u{030A}"), ('\u{1E99}', "y\u{030A}"), ('\u{1E9A}', "a\u{02BE}"),
+    ('\u{1E9B}', "\u{1E61}"), ('\u{1E9E}', "ss"), ('\u{1F50}', "\u{03C5}\u{0313}"),
+    ('\u{1F52}', "\u{03C5}\u{0313}\u{0300}"), ('\u{1F54}', "\u{03C5}\u{0313}\u{0301}"),
+    ('\u{1F56}', "\u{03C5}\u{0313}\u{0342}"), ('\u{1FB2}', "\u{1F70}\u{03B9}"),
+    ('\u{1FB3}', "\u{03B1}\u{03B9}"), ('\u{1FB4}', "\u{03AC}\u{03B9}"),
+    ('\u{1FB6}', "\u{03B1}\u{0342}"), ('\u{1FB7}', "\u{03B1}\u{0342}\u{03B9}"),
+    ('\u{1FBC}', "\u{03B1}\u{03B9}"), ('\u{1FBE}', "\u{03B9}"), ('\u{1FC2}', "\u{1F74}\u{03B9}"),
+    ('\u{1FC3}', "\u{03B7}\u{03B9}"), ('\u{1FC4}', "\u{03AE}\u{03B9}"),
+    ('\u{1FC6}', "\u{03B7}\u{0342}"), ('\u{1FC7}', "\u{03B7}\u{0342}\u{03B9}"),
+    ('\u{1FCC}', "\u{03B7}\u{03B9}"), ('\u{1FD2}', "\u{03B9}\u{0308}\u{0300}"),
+    ('\u{1FD3}', "\u{03B9}\u{0308}\u{0301}"), ('\u{1FD6}', "\u{03B9}\u{0342}"),
+    ('\u{1FD7}', "\u{03B9}\u{0308}\u{0342}"), ('\u{1FE2}', "\u{03C5}\u{0308}\u{0300}"),
+    ('\u{1FE3}', "\u{03C5}\u{0308}\u{0301}"), ('\u{1FE4}', "\u{03C1}\u{0313}"),
+    ('\u{1FE6}', "\u{03C5}\u{0342}"), ('\u{1FE7}', "\u{03C5}\u{0308}\u{0342}"),
+    ('\u{1FF2}', "\u{1F7C}\u{03B9}"), ('\u{1FF3}', "\u{03C9}\u{03B9}"),
+    ('\u{1FF4}', "\u{03CE}\u{03B9}"), ('\u{1FF6}', "\u{03C9}\u{0342}"),
+    ('\u{1FF7}', "\u{03C9}\u{0342}\u{03B9}"), ('\u{1FFC}', "\u{03C9}\u{03B9}"), ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"), ('\u{FB02}', "fl"), ('\u{FB03}', "ffi"), ('\u{FB04}', "ffl"),
+    ('\u{FB05}', "st"), ('\u{FB06}', "st"), ('\u{FB13}', "\u{0574}\u{0576}"),
+    ('\u{FB14}', "\u{0574}\u{0565}"), ('\u{FB15}', "\u{0574}\u{056B}"),
+    ('\u{FB16}', "\u{057E}\u{0576}"), ('\u{FB17}', "\u{0574}\u{056D}"),
+];
+
+fn split(s: &str, sep: Option<&str>, limit: Option<usize>, from_right: bool) -> Result<Vec<String>> {
+    let mut parts: Vec<String> = match sep {
+        Some("") => return Err(Exception::value_error("str.split(): leeres Trennzeichen")),
+        Some(sep) => match (limit, from_right) {
+            (Some(n), false) => s.splitn(n + 1, sep).map(String::from).collect(),
+            (Some(n), true) => s.rsplitn(n + 1, sep).map(String::from).collect(),
+            (None, _) => s.split(sep).map(String::from).collect(),
+        },
+        // Ohne Trennzeichen trennt jeder Leerraum, leere Teile entfallen
+        None => {
+            let words: Vec<&str> = s.split_whitespace().collect();
+            match limit {
+                Some(n) if n < words.len() => {
+                    if from_right {
+                        let keep = words.len() - n;
+                        let rest = s.trim_end();
+                        let mut tail: Vec<String> = words[keep..].iter().rev().map(|w| w.to_string()).collect();
+                        let mut head = rest;
+                        for word in words[keep..].iter().rev() {
+                            head = head.trim_end().strip_suffix(word).unwrap_or(head);
+                        }
+                        tail.push(head.trim_end().to_string());
+                        tail
+                    } else {
+                        let mut rest = s.trim_start();
+                        let mut head = Vec::new();
+                        for word in &words[..n] {
+                            rest = rest.trim_start().strip_prefix(word).unwrap_or(rest);
+                            head.push(word.to_string());
+                        }
+                        head.push(rest.trim_start().to_string());
+                        head
+                    }
+                }
+                _ => {
+                    let words: Vec<String> = words.into_iter().map(String::from).collect();
+                    if from_right {
+                        words.into_iter().rev().collect()
+                    } else {
+                        words
+                    }
+                }
+            }
+        }
+    };
+    if from_right {
+        parts.reverse();
+    }
+    Ok(parts)
+}
+
+fn split_lines(s: &str, keepends: bool) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current = String::new();
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        if c == '\n' || c == '\r' {
+            let mut ending = c.to_string();
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                ending.push(chars.next().unwrap());
+            }
+            if keepends {
+                current.push_str(&ending);
+            }
+            lines.push(std::mem::take(&mut current));
+        } else {
+            current.push(c);
+        }
+    }
+    if !current.is_empty() {
+        lines.push(current);
+    }
+    lines
+}
+
+// Teilstück `s[start:end]` in Zeichen, zusammen mit der Zeichenposition seines Anfangs
+fn char_window<'a>(
+    s: &'a str,
+    start: Option<Value>,
+    end: Option<Value>,
+    method: &str,
+) -> Result<(&'a str, usize)> {
+    let len = s.chars().count() as i64;
+    let clamp = |index: i64| if index < 0 { (index + len).max(0) } else { index.min(len) } as usize;
+    let start = optional_integer(method, start)?.map_or(0, clamp);
+    let end = optional_integer(method, end)?.map_or(len as usize, clamp);
+    if start >= end {
+        return Ok(("", start));
+    }
+    let byte_at = |pos: usize| s.char_indices().nth(pos).map_or(s.len(), |(i, _)| i);
+    Ok((&s[byte_at(start)..byte_at(end)], start))
+}
+
+fn pad(s: &str, width: usize, fill: char, align: char) -> Result<String> {
+    let width = check_width(width)?;
+    let len = s.chars().count();
+    if len >= width {
+        return Ok(s.to_string());
+    }
+    let total = width - len;
+    let (left, right) = match align {
+        '<' => (0, total),
+        '>' => (total, 0),
+        _ => (total / 2, total - total / 2),
+    };
+    Ok(format!("{}{}{}", repeat_char(fill, left)?, s, repeat_char(fill, right)?))
+}
+
+// Anders als '^' in format() bekommt str.center den ungeraden Rest links, wenn auch die
+// Breite ungerade ist (wie in Python)
+fn center(s: &str, width: usize, fill: char) -> Result<String> {
+    let width = check_width(width)?;
+    let len = s.chars().count();
+    if len >= width {
+        return Ok(s.to_string());
+    }
+    let total = width - len;
+    let left = total / 2 + (total & width & 1);
+    Ok(format!("{}{}{}", repeat_char(fill, left)?, s, repeat_char(fill, total - left)?))
+}
+
+// Größte Breite bzw. Genauigkeit beim Auffüllen und Formatieren
+const MAX_WIDTH: usize = i32::MAX as usize;
+
+// Füllzeichen für eine bereits mit `check_width` geprüfte Breite
+fn repeat_char(c: char, count: usize) -> Result<String> {
+    let mut result = String::new();
+    result
+        .try_reserve_exact(count * c.len_utf8())
+        .map_err(|_| Exception::memory_error())?;
+    result.extend(std::iter::repeat_n(c, count));
+    Ok(result)
+}
+
+// Zu große Breiten sind ein Fehler statt eines Absturzes beim Reservieren
+fn check_width(width: usize) -> Result<usize> {
+    if width > MAX_WIDTH {
+        return Err(Exception::new(
+            "OverflowError",
+            format!("Breite {} ist zu groß (höchstens {})", width, MAX_WIDTH),
+        ));
+    }
+    Ok(width)
+}
+
+// Erste Ziffer jedes Blocks von zehn Dezimalziffern (Kategorie Nd) bis Unicode 15
+const DECIMAL_ZEROS: &[u32] = &[
+    0x30, 0x660, 0x6f0, 0x7c0, 0x966, 0x9e6, 0xa66, 0xae6, 0xb66, 0xbe6, 0xc66, 0xce6, 0xd66,
+    0xde6, 0xe50, 0xed0, 0xf20, 0x1040, 0x1090, 0x17e0, 0x1810, 0x1946, 0x19d0, 0x1a80, 0x1a90,
+    0x1b50, 0x1bb0, 0x1c40, 0x1c50, 0xa620, 0xa8d0, 0xa900, 0xa9d0, 0xa9f0, 0xaa50, 0xabf0,
+    0xff10, 0x104a0, 0x10d30, 0x11066, 0x110f0, 0x11136, 0x111d0, 0x112f0, 0x11450, 0x114d0,
+    0x11650, 0x116c0, 0x11730, 0x118e0, 0x11950, 0x11c50, 0x11d50, 0x11da0, 0x11f50, 0x16a60,
+    0x16ac0, 0x16b50, 0x1d7ce, 0x1d7d8, 0x1d7e2, 0x1d7ec, 0x1d7f6, 0x1e140, 0x1e2f0, 0x1e4f0,
+    0x1e950, 0x1fbf0,
+];
+
+// Ziffern, die keine Dezimalziffern sind: hoch- und tiefgestellte, eingekreiste usw.
+const OTHER_DIGITS: &[(u32, u32)] = &[
+    (0xb2, 0xb3), (0xb9, 0xb9), (0x1369, 0x1371), (0x19da, 0x19da), (0x2070, 0x2070),
+    (0x2074, 0x2079), (0x2080, 0x2089), (0x2460, 0x2468), (0x2474, 0x247c), (0x2488, 0x2490),
+    (0x24ea, 0x24ea), (0x24f5, 0x24fd), (0x24ff, 0x24ff), (0x2776, 0x277e), (0x2780, 0x2788),
+    (0x278a, 0x2792), (0x10a40, 0x10a43), (0x10e60, 0x10e68), (0x11052, 0x1105a),
+    (0x1f100, 0x1f10a),
+];
+
+fn is_decimal(c: char) -> bool {
+    let code = c as u32;
+    DECIMAL_ZEROS.iter().any(|&zero| (zero..zero + 10).contains(&code))
+}
+
+// Wie `str.isdigit` in Python: Dezimalziffern und die übrigen Ziffernzeichen
+fn is_digit(c: char) -> bool {
+    let code = c as u32;
+    is_decimal(c) || OTHER_DIGITS.iter().any(|&(first, last)| (first..=last).contains(&code))
+}
+
+// `"{} {name} {0:>5}".format(...)` mit Formatangaben [[fill]align][sign][0][width][,][.precision][type]
+fn format_string(
+    interpreter: &mut Interpreter,
+    template: &str,
+    args: &[Value],
+    kwargs: &[(String, Value)],
+) -> Result<String> {
+    let mut result = String::new();
+    let mut chars = template.chars().peekable();
+    let mut next_auto = 0;
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+                result.push('{');
+            }
+            '}' if chars.peek() == Some(&'}') => {
+                chars.next();
+                result.push('}');
+            }
+            '{' => {
+                let mut field = String::new();
+                loop {
+                    match chars.next() {
+                        Some('}') => break,
+                        Some(c) => field.push(c),
+                        None => {
+                            return Err(Exception::value_error(
+                                "str.format(): '{' ohne schließendes '}'",
+                            ))
+                        }
+                    }
+                }
+                let (name, spec) = match field.split_once(':') {
+                    Some((name, spec)) => (name.to_string(), spec.to_string()),
+                    None => (field, String::new()),
+                };
+                let value = if name.is_empty() {
+                    next_auto += 1;
+                    args.get(next_auto - 1)
+                        .ok_or_else(|| Exception::index_error("str.format(): zu wenige Argumente"))?
+                } else if let Ok(index) = name.parse::<usize>() {
+                    args.get(index).ok_or_else(|| {
+                        Exception::index_error(format!("str.format(): kein Argument mit Index {}", index))
+                    })?
+                } else {
+                    kwargs
+                        .iter()
+                        .find(|(key, _)| *key == name)
+                        .map(|(_, value)| value)
+                        .ok_or_else(|| Exception::key_error(format!("str.format(): kein Argument '{}'", name)))?
+                };
+                result.push_str(&format_value(interpreter, value, &spec)?);
+            }
+            '}' => {
+                return Err(Exception::value_error(
+                    "str.format(): einzelnes '}' in der Vorlage",
+                ))
+            }
+            _ => result.push(c),
+        }
+    }
+    Ok(result)
+}
+
+fn format_value(interpreter: &mut Interpreter, value: &Value, spec: &str) -> Result<String> {
+    let mut spec: Vec<char> = spec.chars().collect();
+    let mut fill = ' ';
+    let mut align = None;
+    if spec.len() >= 2 && matches!(spec[1], '<' | '>' | '^') {
+        fill = spec[0];
+        align = Some(spec[1]);
+        spec.drain(..2);
+    } else if !spec.is_empty() && matches!(spec[0], '<' | '>' | '^') {
+        align = Some(spec[0]);
+        spec.remove(0);
+    }
+    let mut sign = '-';
+    if !spec.is_empty() && matches!(spec[0], '+' | '-' | ' ') {
+        sign = spec.remove(0);
+    }
+    let mut zero_pad = false;
+    if !spec.is_empty() && spec[0] == '0' {
+        zero_pad = true;
+        spec.remove(0);
+    }
+    let rest: String = spec.into_iter().collect();
+    let digits_end = rest.find(|c: char| !c.is_ascii_digit()).unwrap_or(rest.len());
+    let width = match &rest[..digits_end] {
+        "" => 0,
+        digits => check_width(digits.parse().unwrap_or(usize::MAX))?,
+    };
+    let mut rest = &rest[digits_end..];
+    let grouping = rest.starts_with(',');
+    if grouping {
+        rest = &rest[1..];
+    }
+    let mut precision = None;
+    if let Some(stripped) = rest.strip_prefix('.') {
+        let end = stripped.find(|c: char| !c.is_ascii_digit()).unwrap_or(stripped.len());
+        let digits = &stripped[..end];
+        if digits.is_empty() {
+            return Err(Exception::value_error(
+                "str.format(): ungültige Genauigkeit in Formatangabe",
+            ));
+        }
+        precision = Some(check_width(digits.parse().unwrap_or(usize::MAX))?);
+        rest = &stripped[end..];
+    }
+    let kind = rest.chars().next();
+
+    let body = match (value, kind) {
+        (Value::Number(n), kind) if kind != Some('s') => {
+            let magnitude = n.abs();
+            let mut digits = match kind {
+                Some('d') => {
+                    if n.fract() != 0.0 {
+                        return Err(Exception::value_error(
+                            "str.format(): Format 'd' erwartet eine ganze Zahl",
+                        ));
+                    }
+                    format!("{}", magnitude)
+                }
+                Some('f') | Some('F') => format!("{:.*}", precision.unwrap_or(6), magnitude),
+                Some('e') => format!("{:.*e}", precision.unwrap_or(6), magnitude),
+                Some('%') => format!("{:.*}%", precision.unwrap_or(6), magnitude * 100.0),
+                Some('x') => format!("{:x}", magnitude as i64),
+                Some('X') => format!("{:X}", magnitude as i64),
+                Some('o') => format!("{:o}", magnitude as i64),
+                Some('b') => format!("{:b}", magnitude as i64),
+                None => match precision {
+                    Some(p) => format!("{:.*}", p, magnitude),
+                    None => magnitude.to_string(),
+                },
+                Some(other) => {
+                    return Err(Exception::value_error(format!(
+                        "str.format(): unbekannter Formattyp '{}'",
+                        other
+                    )))
+                }
+            };
+            if grouping {
+                digits = group_thousands(&digits);
+            }
+            let sign_str = if *n < 0.0 {
+                "-"
+            } else {
+                match sign {
+                    '+' => "+",
+                    ' ' => " ",
+                    _ => "",
+                }
+            };
+            if zero_pad && align.is_none() {
+                let len = sign_str.chars().count() + digits.chars().count();
+                let zeros = repeat_char('0', width.saturating_sub(len))?;
+                return Ok(format!("{}{}{}", sign_str, zeros, digits));
+            }
+            format!("{}{}", sign_str, digits)
+        }
+        (_, Some('s') | None) => {
+            let text = interpreter.value_to_string(value)?;
+            match precision {
+                Some(p) => text.chars().take(p).collect(),
+                None => text,
+            }
+        }
+        (_, Some(kind)) => {
+            return Err(Exception::value_error(format!(
+                "str.format(): Format '{}' ist für '{}' nicht möglich",
+                kind,
+                type_name(value)
+            )))
+        }
+    };
+    // Zahlen werden standardmäßig rechts, alles andere links ausgerichtet
+    let default_align = if matches!(value, Value::Number(_)) { '>' } else { '<' };
+    pad(&body, width, fill, align.unwrap_or(default_align))
+}
+
+fn group_thousands(digits: &str) -> String {
+    let (int_part, frac_part) = match digits.find('.') {
+        Some(pos) => (&digits[..pos], &digits[pos..]),
+        None => (digits, ""),
+    };
+    let mut grouped = String::new();
+    for (i, c) in int_part.chars().enumerate() {
+        if i > 0 && (int_part.len() - i) % 3 == 0 {
+            grouped.push(',');
+        }
+        grouped.push(c);
+    }
+    grouped + frac_part
+}
+
+// Ordnet Positions- und Schlüsselwortargumente den Parameternamen zu
+fn bind<const N: usize>(
+    method: &str,
+    params: &[&str; N],
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<[Option<Value>; N]> {
+    if args.len() > N {
+        return Err(Exception::type_error(format!(
+            "{}() erwartet höchstens {} Argument(e), erhalten {}",
+            method,
+            N,
+            args.len()
+        )));
+    }
+    let mut bound: [Option<Value>; N] = std::array::from_fn(|_| None);
+    for (i, arg) in args.into_iter().enumerate() {
+        bound[i] = Some(arg);
+    }
+    for (key, value) in kwargs {
+        match params.iter().position(|p| *p == key) {
+            Some(i) if bound[i].is_none() => bound[i] = Some(value),
+            Some(_) => {
+                return Err(Exception::type_error(format!(
+                    "{}(): mehrfacher Wert für '{}'",
+                    method, key
+                )))
+            }
+            None => {
+                return Err(Exception::type_error(format!(
+                    "{}(): unbekanntes Schlüsselwortargument '{}'",
+                    method, key
+                )))
+            }
+        }
+    }
+    Ok(bound)
+}
+
+fn required(method: &str, value: Option<Value>) -> Result<Value> {
+    value.ok_or_else(|| Exception::type_error(format!("{}(): fehlendes Argument", method)))
+}
+
+fn required_string(method: &str, value: Option<Value>) -> Result<String> {
+    match required(method, value)? {
+        Value::String(s) => Ok(s),
+        other => Err(Exception::type_error(format!(
+            "{}() erwartet 'str', nicht '{}'",
+            method,
+            type_name(&other)
+        ))),
+    }
+}
+
+fn optional_string(method: &str, value: Option<Value>) -> Result<Option<String>> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(other) => Err(Exception::type_error(format!(
+            "{}() erwartet 'str', nicht '{}'",
+            method,
+            type_name(&other)
+        ))),
+    }
+}
+
+fn required_integer(method: &str, value: Option<Value>) -> Result<i64> {
+    optional_integer(method, value)?
+        .ok_or_else(|| Exception::type_error(format!("{}(): fehlendes Argument", method)))
+}
+
+fn optional_integer(method: &str, value: Option<Value>) -> Result<Option<i64>> {
+    match value {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::Number(n)) if n.fract() == 0.0 => Ok(Some(n as i64)),
+        Some(other) => Err(Exception::type_error(format!(
+            "{}() erwartet eine ganze Zahl, nicht '{}'",
+            method,
+            type_name(&other)
+        ))),
+    }
+}
@@ -0,0 +1,150 @@
+// src/threading.rs
+
+use crate::builtins::reject_kwargs;
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::{type_name, Value};
+use crate::modules::expect_args;
+use std::cell::Cell;
+use std::fmt;
+use std::rc::Rc;
+
+pub const FUNCTIONS: &[&str] = &["Lock"];
+
+pub const LOCK_METHODS: &[&str] = &["acquire", "release", "locked", "__enter__", "__exit__"];
+
+// Eine einfache Sperre. Lumina läuft in nur einem Thread: wer eine gehaltene Sperre
+// blockierend anfordert, würde ewig warten, deshalb ist das ein RuntimeError.
+#[derive(Default)]
+pub struct Lock {
+    held: Cell<bool>,
+}
+
+impl fmt::Debug for Lock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.describe())
+    }
+}
+
+// Sperren sind nur mit sich selbst gleich
+impl PartialEq for Lock {
+    fn eq(&self, other: &Self) -> bool {
+        std::ptr::eq(self, other)
+    }
+}
+
+impl Lock {
+    pub fn describe(&self) -> &'static str {
+        if self.held.get() {
+            "<locked lock>"
+        } else {
+            "<unlocked lock>"
+        }
+    }
+
+    fn acquire(&self, blocking: bool) -> Result<bool> {
+        if !self.held.get() {
+            self.held.set(true);
+            return Ok(true);
+        }
+        if blocking {
+            return Err(Exception::new(
+                "RuntimeError",
+                "lock.acquire(): Sperre ist bereits gehalten und würde nie freigegeben",
+            ));
+        }
+        Ok(false)
+    }
+
+    fn release(&self) -> Result<()> {
+        if !self.held.replace(false) {
+            return Err(Exception::new("RuntimeError", "lock.release(): Sperre ist nicht gehalten"));
+        }
+        Ok(())
+    }
+}
+
+pub fn call_lock_method(
+    lock: &Rc<Lock>,
+    name: &str,
+    args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    let method = format!("lock.{}", name);
+    if name == "acquire" {
+        return acquire(lock, &method, args, kwargs);
+    }
+    reject_kwargs(&method, &kwargs)?;
+    match name {
+        "release" => {
+            expect_args(&method, &args, 0, 0)?;
+            lock.release()?;
+            Ok(Value::Null)
+        }
+        "locked" => {
+            expect_args(&method, &args, 0, 0)?;
+            Ok(Value::Boolean(lock.held.get()))
+        }
+        // Kontextmanager: `with` hält die Sperre für die Dauer des Blocks
+        "__enter__" => {
+            expect_args(&method, &args, 0, 0)?;
+            Ok(Value::Boolean(lock.acquire(true)?))
+        }
+        "__exit__" => {
+            expect_args(&method, &args, 3, 3)?;
+            lock.release()?;
+            Ok(Value::Boolean(false))
+        }
+        _ => Err(Exception::attribute_error(format!(
+            "'lock' hat keine Methode '{}'",
+            name
+        ))),
+    }
+}
+
+// lock.acquire(blocking=true)
+fn acquire(
+    lock: &Lock,
+    method: &str,
+    mut args: Vec<Value>,
+    kwargs: Vec<(String, Value)>,
+) -> Result<Value> {
+    for (key, value) in kwargs {
+        match key.as_str() {
+            "blocking" if args.is_empty() => args.push(value),
+            _ => {
+                return Err(Exception::type_error(format!(
+                    "Unbekanntes Schlüsselwortargument '{}' für {}()",
+                    key, method
+                )))
+            }
+        }
+    }
+    expect_args(method, &args, 0, 1)?;
+    let blocking = match args.first() {
+        None => true,
+        Some(Value::Boolean(blocking)) => *blocking,
+        Some(other) => {
+            return Err(Exception::type_error(format!(
+                "{}(): blocking muss 'bool' sein, nicht '{}'",
+                method,
+                type_name(other)
+            )))
+        }
+    };
+    Ok(Value::Boolean(lock.acquire(blocking)?))
+}
+
+pub fn call(name: &str, args: Vec<Value>, kwargs: Vec<(String, Value)>) -> Result<Value> {
+    let method = format!("threading.{}", name);
+    reject_kwargs(&method, &kwargs)?;
+    match name {
+        "Lock" => {
+            expect_args(&method, &args, 0, 0)?;
+            Ok(Value::Lock(Rc::new(Lock::default())))
+        }
+        _ => Err(Exception::attribute_error(format!(
+            "Modul 'threading' hat kein Attribut '{}'",
+            name
+        ))),
+    }
+}
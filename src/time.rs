@@ -0,0 +1,52 @@
+// src/time.rs
+
+use crate::builtins::reject_kwargs;
+use crate::exceptions::{Exception, Result};
+use crate::interpreter::Value;
+use crate::modules::{expect_args, number};
+use std::sync::OnceLock;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+pub const FUNCTIONS: &[&str] = &["time", "monotonic", "perf_counter", "sleep"];
+
+// Bezugspunkt der monotonen Uhr; nur Differenzen ihrer Werte sind aussagekräftig
+static START: OnceLock<Instant> = OnceLock::new();
+
+pub fn call(name: &str, args: Vec<Value>, kwargs: Vec<(String, Value)>) -> Result<Value> {
+    let method = format!("time.{}", name);
+    reject_kwargs(&method, &kwargs)?;
+    match name {
+        // Sekunden seit 1970-01-01 UTC
+        "time" => {
+            expect_args(&method, &args, 0, 0)?;
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0.0, |elapsed| elapsed.as_secs_f64());
+            Ok(Value::Number(now))
+        }
+        "monotonic" | "perf_counter" => {
+            expect_args(&method, &args, 0, 0)?;
+            let start = START.get_or_init(Instant::now);
+            Ok(Value::Number(start.elapsed().as_secs_f64()))
+        }
+        "sleep" => {
+            expect_args(&method, &args, 1, 1)?;
+            let seconds = number(&method, &args[0])?;
+            if !(seconds >= 0.0 && seconds.is_finite()) {
+                return Err(Exception::value_error(
+                    "time.sleep(): Dauer muss eine nicht negative Zahl sein",
+                ));
+            }
+            let duration = Duration::try_from_secs_f64(seconds).map_err(|_| {
+                Exception::new("OverflowError", "time.sleep(): Dauer ist zu groß")
+            })?;
+            thread::sleep(duration);
+            Ok(Value::Null)
+        }
+        _ => Err(Exception::attribute_error(format!(
+            "Modul 'time' hat kein Attribut '{}'",
+            name
+        ))),
+    }
+}
@@ -1,5 +1,6 @@
 // src/token.rs
 
+#[allow(clippy::upper_case_acronyms)]
 #[derive(Debug, PartialEq, Clone)]
 pub enum Token {
     Identifier(String),
@@ -9,19 +10,38 @@ pub enum Token {
     If,
     Else,
     While,
+    For,
     Def,
+    Class,
     Return,
+    Try,
+    Except,
+    Finally,
+    Raise,
+    Assert,
+    Import,
+    From,
+    With,
+    As,
+    Lambda,
     Print,
     True,
     False,
+    None,
     And,
     Or,
     Not,
+    In,
+    Is,
     Assign,
+    AugAssign(String), // Erweiterte Zuweisung, z. B. '+=' mit Operator "+"
     LeftParen,
     RightParen,
     LeftBracket,    // Hinzugefügt: LeftBracket für Listen
     RightBracket,   // Hinzugefügt: RightBracket für Listen
+    LeftBrace,      // Dicts und Sets
+    RightBrace,
+    Dot,            // Attributzugriff und Methodenaufrufe
     Comma,          // Bereits vorhanden, kann für Listen und Tupel genutzt werden
     Colon,
     Newline,
@@ -29,3 +49,14 @@ pub enum Token {
     Dedent,
     EOF,
 }
+
+// Position eines Tokens: Quelldatei (Index in der Liste des Interpreters), Zeile und Spalte ab 1.
+// Das Ende zeigt hinter das letzte Zeichen.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub struct Span {
+    pub file: usize,
+    pub line: usize,
+    pub column: usize,
+    pub end_line: usize,
+    pub end_column: usize,
+}